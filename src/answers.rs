@@ -0,0 +1,253 @@
+//! Known-good answers, stored in a small subset of TOML:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 1234
+//! part2 = "some text"
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::error::{Error, Result};
+use crate::runner::{Outcome, RunResult};
+use crate::solution::Answer;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    /// keyed by `(day, part)`
+    pub entries: BTreeMap<(u32, u32), Answer>,
+}
+
+impl Answers {
+    /// loads the answers file, treating a missing file as empty
+    pub fn load(path: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|e| e.with_text(path)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::from(e).with_text(path)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        std::fs::write(path, self.to_toml()).map_err(|e| Error::from(e).with_text(path))
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        let mut day = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let d = header
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u32>().ok())
+                    .ok_or_else(|| Error::unexpected(line).with_line(i + 1))?;
+                day = Some(d);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::missing("=").with_line(i + 1).with_text(line))?;
+            let part = key
+                .trim()
+                .strip_prefix("part")
+                .and_then(|p| p.parse::<u32>().ok())
+                .ok_or_else(|| Error::unexpected(key.trim()).with_line(i + 1))?;
+            let day = day.ok_or_else(|| {
+                Error::other("answer outside of a [dayNN] section").with_line(i + 1)
+            })?;
+            let value = parse_value(value.trim()).map_err(|e| e.with_line(i + 1))?;
+
+            answers.entries.insert((day, part), value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut s = String::new();
+        let mut current_day = None;
+        for (&(day, part), answer) in &self.entries {
+            let value = match answer {
+                Answer::Int(n) => n.to_string(),
+                Answer::Str(v) => quote(v),
+                Answer::Unimplemented => continue,
+            };
+
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    s.push('\n');
+                }
+                s.push_str(&format!("[day{day:02}]\n"));
+                current_day = Some(day);
+            }
+            s.push_str(&format!("part{part} = {value}\n"));
+        }
+        s
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Answer> {
+        self.entries.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: Answer) -> Option<Answer> {
+        self.entries.insert((day, part), answer)
+    }
+}
+
+fn parse_value(s: &str) -> Result<Answer> {
+    if let Some(quoted) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        let mut value = String::new();
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some(c @ ('"' | '\\')) => value.push(c),
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .filter(|_| hex.len() == 4)
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| Error::other("invalid escape sequence").with_text(s))?;
+                        value.push(c);
+                    }
+                    _ => return Err(Error::other("invalid escape sequence").with_text(s)),
+                },
+                c => value.push(c),
+            }
+        }
+        Ok(Answer::Str(value))
+    } else {
+        Ok(Answer::Int(s.replace('_', "").parse::<i128>().map_err(
+            |_| Error::other("expected an integer or a quoted string").with_text(s),
+        )?))
+    }
+}
+
+/// a quoted string, using only the escapes [`parse_value`] understands
+fn quote(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// there's no stored answer to compare against
+    Missing(Answer),
+    /// there was no stored answer, and this one has now been stored
+    Recorded(Answer),
+    Error,
+    Skipped,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error)
+    }
+}
+
+/// Compares a run against the stored answers. With `record`, answers that
+/// weren't known yet are added to `answers`; existing answers are never overwritten.
+pub fn check(result: &RunResult, answers: &mut Answers, record: bool) -> Verdict {
+    let actual = match &result.outcome {
+        Outcome::Solved(Answer::Unimplemented) | Outcome::MissingInput => return Verdict::Skipped,
+        Outcome::Failed(_) | Outcome::TimedOut(_) => return Verdict::Error,
+        Outcome::Solved(answer) => answer,
+    };
+
+    match answers.get(result.day, result.part) {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.clone(),
+            actual: actual.clone(),
+        },
+        None if record => {
+            answers.insert(result.day, result.part, actual.clone());
+            Verdict::Recorded(actual.clone())
+        }
+        None => Verdict::Missing(actual.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let toml = r#"# known answers
+[day01]
+part1 = 1_234
+part2 = "a\tb\u00e9"
+
+[ day12 ]
+part1 = -5
+"#;
+        let answers = Answers::parse(toml).unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Int(1234)));
+        assert_eq!(
+            answers.get(1, 2),
+            Some(&Answer::Str("a\tb\u{e9}".to_string()))
+        );
+        assert_eq!(answers.get(12, 1), Some(&Answer::Int(-5)));
+
+        assert!(Answers::parse("part1 = 1\n").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"\\x\"\n").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"\\u12\"\n").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, Answer::Int(42));
+        answers.insert(1, 2, Answer::Str("a\rb\n\"c\" \\ \u{7} é".to_string()));
+        answers.insert(3, 1, Answer::Unimplemented);
+        answers.insert(5, 2, Answer::Int(-1));
+
+        let toml = answers.to_toml();
+        // no empty section for a day without answers
+        assert!(!toml.contains("[day03]"));
+        let expected = r#"[day01]
+part1 = 42
+part2 = "a\rb\n\"c\" \\ \u0007 é"
+
+[day05]
+part2 = -1
+"#;
+        assert_eq!(toml, expected);
+
+        answers.entries.remove(&(3, 1));
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+}
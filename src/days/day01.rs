@@ -0,0 +1,80 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+
+/// the rotations, negative for left
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    let mut rotations = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let (dir, n) = line.split_at_checked(1).unwrap_or((line, ""));
+        let n: i32 = n.parse().on_line(i + 1, line)?;
+
+        rotations.push(match dir {
+            "L" => -n,
+            "R" => n,
+            _ => return Err(Error::unexpected(dir).with_line(i + 1).with_column(1)),
+        });
+    }
+    Ok(rotations)
+}
+
+pub fn part1(rotations: &[i32]) -> Result<Answer> {
+    let mut dial = 50;
+    let mut count = 0;
+    for &rot in rotations {
+        dial += rot;
+        dial %= 100;
+        if dial == 0 {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub fn part2(rotations: &[i32]) -> Result<Answer> {
+    let mut dial: i32 = 50;
+    let mut count = 0;
+    for &rot in rotations {
+        let mut full_rots = i32::abs(rot / 100);
+        let rem = rot % 100;
+
+        if (dial > 0 && dial + rem <= 0) || dial + rem >= 100 {
+            full_rots += 1;
+        }
+        count += full_rots;
+
+        dial = (dial + rot).rem_euclid(100);
+    }
+
+    Ok(count.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<i32>;
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Secret Entrance"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day01.txt").expect(1, 3).expect(2, 6)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
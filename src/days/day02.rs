@@ -0,0 +1,135 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+
+fn num_digits(i: u64) -> u32 {
+    // 0 has no logarithm, but still takes a digit to write
+    i.checked_ilog10().unwrap_or(0) + 1
+}
+
+#[inline]
+fn first_half(i: u64, n: u32) -> u64 {
+    i / 10_u64.pow(n / 2)
+}
+
+#[inline]
+fn last_half(i: u64, n: u32) -> u64 {
+    i % 10_u64.pow(n / 2)
+}
+
+fn is_valid1(i: u64) -> bool {
+    let n = num_digits(i);
+    if !n.is_multiple_of(2) {
+        return true;
+    }
+
+    first_half(i, n) != last_half(i, n)
+}
+
+fn digit_string(mut i: u64, buf: &mut Vec<u8>) {
+    buf.clear();
+    let n = num_digits(i) as usize;
+    buf.resize(n, 0);
+
+    for k in (0..n).rev() {
+        buf[k] = (i % 10) as u8;
+        i /= 10;
+    }
+}
+
+fn is_valid2(s: &[u8]) -> bool {
+    for n in 1..=s.len() / 2 {
+        let mut chunks = s.chunks_exact(n);
+        if !chunks.remainder().is_empty() {
+            continue;
+        }
+
+        let Some(first) = chunks.next() else {
+            continue;
+        };
+
+        if chunks.all(|c| c == first) {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_range(range: &str) -> Result<(u64, u64)> {
+    let (a, b) = range
+        .split_once('-')
+        .ok_or_else(|| Error::missing("-").with_text(range))?;
+    let a = a.parse::<u64>().on_line(1, range)?;
+    let b = b.parse::<u64>().on_line(1, range)?;
+    Ok((a, b))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(u64, u64)>> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .map(parse_range)
+        .collect()
+}
+
+pub fn part1(ranges: &[(u64, u64)]) -> Result<Answer> {
+    let mut total: u64 = 0;
+    for &(a, b) in ranges {
+        for i in a..=b {
+            if !is_valid1(i) {
+                total += i;
+            }
+        }
+    }
+
+    Ok(total.into())
+}
+
+pub fn part2(ranges: &[(u64, u64)]) -> Result<Answer> {
+    let mut total: u64 = 0;
+    let mut scratch = vec![];
+    for &(a, b) in ranges {
+        for i in a..=b {
+            digit_string(i, &mut scratch);
+            if !is_valid2(&scratch) {
+                total += i;
+            }
+        }
+    }
+
+    Ok(total.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<(u64, u64)>;
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Gift Shop"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            example!("day02.txt")
+                .expect(1, 1227775554)
+                .expect(2, 4174379265u64),
+        ]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+
+fn bank_max_n(bank: &[u64], n: usize) -> u64 {
+    let mut table = vec![vec![0; bank.len()]; n + 1];
+
+    for k in 1..=n {
+        for i in (0..bank.len()).rev() {
+            if k > bank.len() - i {
+                continue;
+            }
+            if k == 1 {
+                if i == bank.len() - 1 {
+                    table[k][i] = bank[i];
+                } else {
+                    table[k][i] = u64::max(bank[i], table[k][i + 1]);
+                }
+                continue;
+            }
+
+            table[k][i] = u64::max(
+                bank[i] * 10u64.pow(k as u32 - 1) + table[k - 1][i + 1],
+                table[k][i + 1],
+            );
+        }
+    }
+
+    table[n][0]
+}
+
+fn parse_bank(s: &str) -> Result<Vec<u64>> {
+    if s.is_empty() {
+        return Err(Error::other("empty bank"));
+    }
+    let mut bank = Vec::with_capacity(s.len());
+    for (i, c) in s.bytes().enumerate() {
+        if !c.is_ascii_digit() {
+            return Err(Error::unexpected((c as char).to_string()).with_column(i + 1));
+        }
+        bank.push((c - b'0') as u64);
+    }
+    Ok(bank)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u64>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, bank)| parse_bank(bank).on_line(i + 1, bank))
+        .collect()
+}
+
+pub fn part1(banks: &[Vec<u64>]) -> Result<Answer> {
+    Ok(banks.iter().map(|b| bank_max_n(b, 2)).sum::<u64>().into())
+}
+
+pub fn part2(banks: &[Vec<u64>]) -> Result<Answer> {
+    Ok(banks.iter().map(|b| bank_max_n(b, 12)).sum::<u64>().into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<Vec<u64>>;
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Lobby"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            example!("day03.txt")
+                .expect(1, 357)
+                .expect(2, 3121910778619u64),
+        ]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
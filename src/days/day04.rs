@@ -0,0 +1,79 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Example, example};
+use crate::util::automaton::{Automaton, GridAutomaton};
+use crate::util::grid::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Paper,
+    Empty,
+}
+
+pub fn parse(input: &str) -> Result<Grid<Tile>> {
+    Grid::try_from_str(input, |c| match c {
+        '.' => Ok(Tile::Empty),
+        '@' => Ok(Tile::Paper),
+        _ => Err(Error::unexpected(c.to_string())),
+    })
+}
+
+pub fn part1(grid: &Grid<Tile>) -> Result<Answer> {
+    let mut count = 0;
+    for ((x, y), t) in grid.iter() {
+        if matches!(t, Tile::Empty) {
+            continue;
+        }
+
+        let n = grid
+            .neighbors(x, y, false)
+            .filter(|(_, t)| matches!(t, Tile::Paper))
+            .count();
+        if n < 4 {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub fn part2(grid: &Grid<Tile>) -> Result<Answer> {
+    // paper with fewer than 4 neighbors is removed, until there is none left to remove
+    let mut automaton = GridAutomaton::new(grid.clone(), |t, around| match t {
+        Tile::Paper if around.count(|t| matches!(t, Tile::Paper)) < 4 => Tile::Empty,
+        t => t.clone(),
+    })
+    .with_worklist();
+    let count = automaton.run(None).total();
+
+    Ok(count.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Grid<Tile>;
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Printing Department"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day04.txt").expect(1, 13).expect(2, 43)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
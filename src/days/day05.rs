@@ -0,0 +1,148 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+use crate::util;
+
+#[derive(Debug, Clone)]
+pub struct Ingredients {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Ingredients {
+    fn new() -> Self {
+        Ingredients { ranges: vec![] }
+    }
+
+    fn is_fresh(&self, id: u64) -> bool {
+        self.ranges.iter().any(|&(a, b)| a <= id && id <= b)
+    }
+
+    fn add(&mut self, a: u64, b: u64) {
+        self.ranges.push((a, b));
+    }
+
+    fn merge_intervals(&mut self) {
+        if self.ranges.is_empty() {
+            return;
+        }
+
+        self.ranges.sort();
+
+        let mut merged = vec![];
+        let (mut last_start, mut last_end) = self.ranges[0];
+
+        for (start, end) in std::mem::take(&mut self.ranges) {
+            if last_end < start {
+                // no overlap
+                merged.push((last_start, last_end));
+                last_start = start;
+                last_end = end;
+            } else {
+                last_end = last_end.max(end);
+            }
+        }
+
+        merged.push((last_start, last_end));
+        *self = Ingredients { ranges: merged }
+    }
+
+    fn count(&mut self) -> u64 {
+        self.merge_intervals();
+
+        let mut count = 0;
+        for (a, b) in &self.ranges {
+            count += b - a + 1;
+        }
+
+        count
+    }
+}
+
+fn parse_ranges(fresh: &str) -> Result<Ingredients> {
+    let mut buf = vec![];
+    let mut ingredients = Ingredients::new();
+    for (i, line) in fresh.lines().enumerate() {
+        buf.clear();
+
+        util::parse_ints_buf_u64(line, &mut buf).on_line(i + 1, line)?;
+        if buf.len() != 2 {
+            return Err(Error::other(format!("expected a range, found {} integers", buf.len()))
+                .with_line(i + 1)
+                .with_text(line));
+        }
+        ingredients.add(buf[0], buf[1]);
+    }
+    Ok(ingredients)
+}
+
+pub struct Inventory {
+    ingredients: Ingredients,
+    available: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<Inventory> {
+    let (fresh, available) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::missing("\n\n"))?;
+    let ingredients = parse_ranges(fresh)?;
+
+    let mut available_ids = vec![];
+    let offset = fresh.lines().count() + 1;
+    for (i, line) in available.lines().enumerate() {
+        util::parse_ints_buf_u64(line, &mut available_ids).on_line(offset + i + 1, line)?;
+    }
+
+    Ok(Inventory {
+        ingredients,
+        available: available_ids,
+    })
+}
+
+pub fn part1(inventory: &Inventory) -> Result<Answer> {
+    let mut count = 0;
+    for &id in &inventory.available {
+        if inventory.ingredients.is_fresh(id) {
+            crate::verbose!("ingredient ID {id} is fresh");
+            count += 1;
+        } else {
+            crate::verbose!("ingredient ID {id} is spoiled");
+        }
+    }
+
+    Ok(count.into())
+}
+
+pub fn part2(inventory: &Inventory) -> Result<Answer> {
+    let mut ingredients = inventory.ingredients.clone();
+
+    Ok(ingredients.count().into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Inventory;
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Cafeteria"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day05.txt").expect(1, 3).expect(2, 14)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+use crate::util::{self, Grid};
+
+pub enum Tile {
+    C(u8),
+    Space,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Op {
+    Plus,
+    Times,
+}
+
+/// The worksheet read both ways: row by row for part 1, and as a grid of
+/// characters for part 2, which reads the numbers column by column.
+pub struct Worksheet {
+    rows: Vec<Vec<u64>>,
+    ops: Vec<Op>,
+    grid: Grid<Tile>,
+}
+
+pub fn parse(input: &str) -> Result<Worksheet> {
+    let mut rows = vec![];
+    let mut ops = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('*') || line.starts_with('+') {
+            ops = line
+                .split_ascii_whitespace()
+                .map(|s| match s {
+                    "*" => Ok(Op::Times),
+                    "+" => Ok(Op::Plus),
+                    _ => Err(Error::unexpected(s).with_line(i + 1)),
+                })
+                .collect::<Result<_>>()?;
+        } else {
+            rows.push(util::parse_ints_u64(line).on_line(i + 1, line)?);
+        }
+    }
+
+    let grid = Grid::from_str(input, |c| match c {
+        ' ' => Tile::Space,
+        c => Tile::C(c as u8),
+    })?;
+
+    Ok(Worksheet { rows, ops, grid })
+}
+
+pub fn part1(worksheet: &Worksheet) -> Result<Answer> {
+    let mut acc: Vec<u64> = worksheet
+        .ops
+        .iter()
+        .map(|op| match op {
+            Op::Times => 1,
+            Op::Plus => 0,
+        })
+        .collect();
+
+    for row in &worksheet.rows {
+        for ((a, op), x) in acc
+            .iter_mut()
+            .zip(worksheet.ops.iter())
+            .zip(row.iter().copied())
+        {
+            match op {
+                Op::Times => *a *= x,
+                Op::Plus => *a += x,
+            }
+        }
+    }
+
+    Ok(acc.iter().sum::<u64>().into())
+}
+
+pub fn part2(worksheet: &Worksheet) -> Result<Answer> {
+    let grid = &worksheet.grid;
+    let mut problems: Vec<u64> = vec![];
+    // points to the top-left character of a new problem
+    let mut x: i32 = 0;
+    loop {
+        if x as usize >= grid.width {
+            break;
+        }
+
+        //find the operation
+        let (op, mut acc) = match grid[(x, grid.height as i32 - 1)] {
+            Tile::C(b'*') => (Op::Times, 1),
+            Tile::C(b'+') => (Op::Plus, 0),
+            Tile::C(c) => {
+                return Err(Error::unexpected((c as char).to_string())
+                    .with_line(grid.height)
+                    .with_column(x as usize + 1));
+            }
+            Tile::Space => {
+                return Err(Error::other("expected an operator")
+                    .with_line(grid.height)
+                    .with_column(x as usize + 1));
+            }
+        };
+
+        loop {
+            if x as usize >= grid.width {
+                break;
+            }
+
+            let mut all_spaces = true;
+            let mut operand = 0;
+            for (y, t) in grid.col(x as usize).take(grid.height - 1).enumerate() {
+                if let &Tile::C(c) = t {
+                    if !c.is_ascii_digit() {
+                        return Err(Error::unexpected((c as char).to_string())
+                            .with_line(y + 1)
+                            .with_column(x as usize + 1));
+                    }
+                    all_spaces = false;
+                    let n = (c - b'0') as u64;
+                    operand *= 10;
+                    operand += n;
+                }
+            }
+
+            x += 1;
+            if all_spaces {
+                break;
+            } else {
+                match op {
+                    Op::Plus => acc += operand,
+                    Op::Times => acc *= operand,
+                }
+            }
+        }
+        problems.push(acc);
+    }
+
+    Ok(problems.into_iter().sum::<u64>().into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Worksheet;
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Trash Compactor"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day06.txt").expect(1, 4277556).expect(2, 3263827)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Example, example};
+use crate::util::{Dir, Grid, Vec2, vec2};
+
+#[derive(Clone)]
+pub enum Tile {
+    Start,
+    Empty,
+    Splitter,
+    Beam,
+}
+
+pub struct Manifold {
+    grid: Grid<Tile>,
+    start: Vec2,
+}
+
+pub fn parse(input: &str) -> Result<Manifold> {
+    let grid = Grid::try_from_str(input, |c| match c {
+        '.' => Ok(Tile::Empty),
+        'S' => Ok(Tile::Start),
+        '^' => Ok(Tile::Splitter),
+        _ => Err(Error::unexpected(c.to_string())),
+    })?;
+
+    let start = grid
+        .iter()
+        .find(|(_, t)| matches!(t, Tile::Start))
+        .map(|(p, _)| p)
+        .ok_or_else(|| Error::other("no start position"))?;
+
+    Ok(Manifold {
+        grid,
+        start: start.into(),
+    })
+}
+
+pub fn part1(manifold: &Manifold) -> Result<Answer> {
+    let mut grid = manifold.grid.clone();
+
+    let mut num_splits = 0;
+    let mut stack = vec![manifold.start];
+    while let Some(p) = stack.pop() {
+        for (p, t) in grid.ray_mut(p, Dir::Down) {
+            match t {
+                Tile::Beam => break,
+                Tile::Empty => {
+                    *t = Tile::Beam;
+                }
+                Tile::Start => {
+                    // do nothing
+                }
+                Tile::Splitter => {
+                    num_splits += 1;
+                    stack.push(p + vec2(-1, 0));
+                    stack.push(p + vec2(1, 0));
+                    break;
+                }
+            }
+        }
+    }
+    crate::verbose!(
+        "{}",
+        grid.pretty_string(|t| match t {
+            Tile::Beam => "|",
+            Tile::Empty => " ",
+            Tile::Splitter => "^",
+            Tile::Start => "S",
+        })
+    );
+    Ok(num_splits.into())
+}
+
+fn paths_from(grid: &Grid<Tile>, pos: Vec2, memo: &mut HashMap<Vec2, usize>) -> usize {
+    if let Some(n) = memo.get(&pos) {
+        return *n;
+    }
+
+    for (p, t) in grid.ray(pos, Dir::Down) {
+        match t {
+            Tile::Beam => {
+                unreachable!()
+            }
+            Tile::Empty | Tile::Start => {
+                // do nothing
+            }
+            Tile::Splitter => {
+                let mut n = 0;
+                n += paths_from(grid, p + vec2(-1, 0), memo);
+                n += paths_from(grid, p + vec2(1, 0), memo);
+                memo.insert(pos, n);
+                return n;
+            }
+        }
+    }
+
+    // the beam leaves the grid
+    memo.insert(pos, 1);
+    1
+}
+
+pub fn part2(manifold: &Manifold) -> Result<Answer> {
+    let mut memo = HashMap::new();
+    let num_paths = paths_from(&manifold.grid, manifold.start, &mut memo);
+    Ok(num_paths.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Manifold;
+
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Laboratories"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day07.txt").expect(1, 21).expect(2, 40)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
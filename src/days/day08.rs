@@ -0,0 +1,200 @@
+use std::{
+    cmp::{self, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+use crate::util::{self, Vec3, ext::SortedExt};
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct JunctionBox {
+    pos: Vec3,
+}
+
+impl JunctionBox {
+    fn distance_to(self, other: Self) -> u64 {
+        (other.pos - self.pos).sqr_length_u64()
+    }
+}
+
+impl Display for JunctionBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.pos.x, self.pos.y, self.pos.z)
+    }
+}
+
+fn connected_components(
+    vertices: &[JunctionBox],
+    distances: &[(u64, JunctionBox, JunctionBox)],
+    n: usize,
+) -> Vec<usize> {
+    let mut adjacency: HashMap<JunctionBox, Vec<JunctionBox>> = HashMap::new();
+
+    for (_d, a, b) in distances.iter().take(n) {
+        adjacency.entry(*a).or_default().push(*b);
+        adjacency.entry(*b).or_default().push(*a);
+    }
+
+    // bfs over nodes in this subgraph to find connected components
+    let mut explored_components: HashSet<JunctionBox> = HashSet::new();
+    let mut explored: HashSet<JunctionBox> = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    let mut component_sizes = BinaryHeap::new();
+
+    for v in vertices.iter().copied() {
+        if explored_components.contains(&v) {
+            continue;
+        } else {
+            explored_components.insert(v);
+        }
+        queue.clear();
+        queue.push_back(v);
+        explored.clear();
+        while let Some(v) = queue.pop_front() {
+            if explored.contains(&v) {
+                continue;
+            }
+            explored.insert(v);
+
+            for w in adjacency.entry(v).or_default() {
+                queue.push_back(*w);
+            }
+        }
+        explored_components.extend(&explored);
+        component_sizes.push(explored.len());
+    }
+
+    component_sizes.into_iter().collect()
+}
+
+/// The junction boxes, along with every pair of them sorted by distance.
+pub struct Playground {
+    junctions: Vec<JunctionBox>,
+    distances: Vec<(u64, JunctionBox, JunctionBox)>,
+}
+
+pub fn parse(input: &str) -> Result<Playground> {
+    let junctions: Vec<_> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let ints = util::parse_ints(line).on_line(i + 1, line)?;
+            if ints.len() != 3 {
+                return Err(Error::other(format!("expected 3 coordinates, found {}", ints.len()))
+                    .with_line(i + 1)
+                    .with_text(line));
+            }
+            Ok(JunctionBox {
+                pos: Vec3::new(ints[0], ints[1], ints[2]),
+            })
+        })
+        .collect::<Result<_>>()?;
+    if junctions.len() < 2 {
+        return Err(Error::other(format!(
+            "expected at least 2 junction boxes, found {}",
+            junctions.len()
+        )));
+    }
+
+    let mut distances: Vec<(JunctionBox, JunctionBox, u64)> = Vec::new();
+    for i in 0..junctions.len() {
+        for j in i + 1..junctions.len() {
+            let a = junctions[i];
+            let b = junctions[j];
+            let (a, b) = (cmp::min(a, b), cmp::max(a, b));
+
+            distances.push((a, b, a.distance_to(b)));
+        }
+    }
+
+    let distances = distances
+        .into_iter()
+        .map(|(a, b, v)| (v, a, b))
+        .collect::<Vec<_>>()
+        .sorted();
+
+    Ok(Playground {
+        junctions,
+        distances,
+    })
+}
+
+/// the product of the sizes of the three largest circuits after making the
+/// `connections` shortest connections
+fn largest_circuits(playground: &Playground, connections: usize) -> usize {
+    let component_sizes =
+        connected_components(&playground.junctions, &playground.distances, connections)
+            .sorted_by_key(|t| Reverse(*t));
+    component_sizes.into_iter().take(3).product::<usize>()
+}
+
+pub fn part1(playground: &Playground) -> Result<Answer> {
+    Ok(largest_circuits(playground, 1000).into())
+}
+
+pub fn part2(playground: &Playground) -> Result<Answer> {
+    let Playground {
+        junctions,
+        distances,
+    } = playground;
+
+    let i = util::binsearch_leftmost_exponential(|i| {
+        let component_sizes = connected_components(junctions, distances, i + 1);
+        component_sizes.len() == 1
+    });
+
+    let (_, a, b) = distances[i];
+    crate::verbose!("{a} <-> {b}");
+    Ok((a.pos.x as u64 * b.pos.x as u64).into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Playground;
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Playground"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        // part 1 connects 1000 pairs, but the example only connects 10, see `tests`
+        vec![example!("day08.txt").expect(2, 25272)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let input = include_str!("../../examples/day08.txt");
+        assert_eq!(largest_circuits(&parse(input).unwrap(), 10), 40);
+    }
+
+    #[test]
+    fn too_few_junctions() {
+        assert!(parse("").is_err());
+        assert!(parse("1,2,3\n").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
 use crate::util::{self, Vec2, vec2};
 
 fn area(a: Vec2, b: Vec2) -> u64 {
@@ -6,17 +8,23 @@ fn area(a: Vec2, b: Vec2) -> u64 {
     w * h
 }
 
-pub fn part1(input: &str) {
-    let input = util::read_file(input).unwrap();
-
+/// the red tiles, in order around the loop
+pub fn parse(input: &str) -> Result<Vec<Vec2>> {
     let mut tiles = Vec::new();
-    for line in input.lines() {
-        let ints = util::parse_ints(line).unwrap();
-        assert!(ints.len() == 2);
+    for (i, line) in input.lines().enumerate() {
+        let ints = util::parse_ints(line).on_line(i + 1, line)?;
+        if ints.len() != 2 {
+            return Err(Error::other(format!("expected 2 coordinates, found {}", ints.len()))
+                .with_line(i + 1)
+                .with_text(line));
+        }
         let (x, y) = (ints[0], ints[1]);
         tiles.push(vec2(x, y));
     }
+    Ok(tiles)
+}
 
+pub fn part1(tiles: &[Vec2]) -> Result<Answer> {
     let mut max_area = 0;
 
     for i in 0..tiles.len() {
@@ -31,7 +39,7 @@ pub fn part1(input: &str) {
         }
     }
 
-    println!("{max_area}");
+    Ok(max_area.into())
 }
 
 fn intersects_with_interior(p1: Vec2, p2: Vec2, polygon: &[Vec2]) -> bool {
@@ -68,22 +76,9 @@ fn intersects_with_interior(p1: Vec2, p2: Vec2, polygon: &[Vec2]) -> bool {
     false
 }
 
-pub fn part2(input: &str) {
-    let input = util::read_file(input).unwrap();
-
-    let mut polygon = Vec::new();
-    let mut first = None;
-    for line in input.lines() {
-        let ints = util::parse_ints(line).unwrap();
-        assert!(ints.len() == 2);
-        let (x, y) = (ints[0], ints[1]);
-        let p = vec2(x, y);
-        polygon.push(p);
-        if first.is_none() {
-            first = Some(p);
-        }
-    }
-    if let Some(first) = first {
+pub fn part2(tiles: &[Vec2]) -> Result<Answer> {
+    let mut polygon = tiles.to_vec();
+    if let Some(&first) = tiles.first() {
         polygon.push(first);
     }
 
@@ -104,5 +99,35 @@ pub fn part2(input: &str) {
         }
     }
 
-    println!("{max_area}");
+    Ok(max_area.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<Vec2>;
+
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Movie Theater"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day09.txt").expect(1, 50).expect(2, 24)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
 }
@@ -0,0 +1,166 @@
+use good_lp::{IntoAffineExpression, ProblemVariables};
+use good_lp::{Solution, SolverModel, default_solver, variable};
+use regex::Regex;
+
+use crate::error::{Error, Result, ResultExt};
+use crate::solution::{Answer, Example, example};
+use crate::util::{self, search};
+
+#[derive(Debug)]
+pub struct Problem {
+    goal: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<u64>,
+}
+
+fn parse_goal(s: &str) -> Vec<bool> {
+    // the regex in `parse` only lets `.` and `#` through
+    s.bytes().map(|b| b == b'#').collect()
+}
+
+fn parse_buttons(s: &str) -> Result<Vec<Vec<usize>>> {
+    s.split(' ')
+        .map(|s| Ok(util::parse_ints_u32(s)?.into_iter().map(|i| i as usize).collect()))
+        .collect()
+}
+
+fn parse_joltage(s: &str) -> Result<Vec<u64>> {
+    util::parse_ints_u64(s)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Problem>> {
+    let re = Regex::new(r"^\[([.#]+)\]\s([^\{]+)\s\{((?:\d,?)+)\}$").unwrap();
+
+    let mut problems: Vec<Problem> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let Some(captures) = re.captures(line) else {
+            return Err(Error::unexpected(line).with_line(i + 1));
+        };
+        let (_, [goal, buttons, joltage]) = captures.extract();
+        let p = Problem {
+            goal: parse_goal(goal),
+            buttons: parse_buttons(buttons).on_line(i + 1, line)?,
+            joltage: parse_joltage(joltage).on_line(i + 1, line)?,
+        };
+        if let Some(&b) = p.buttons.iter().flatten().find(|&&b| b >= p.goal.len()) {
+            return Err(Error::other(format!("button {b} is out of range"))
+                .with_line(i + 1)
+                .with_text(line));
+        }
+        if p.joltage.len() != p.goal.len() {
+            return Err(Error::other(format!(
+                "{} joltage values for {} lights",
+                p.joltage.len(),
+                p.goal.len()
+            ))
+            .with_line(i + 1)
+            .with_text(line));
+        }
+
+        problems.push(p);
+    }
+
+    Ok(problems)
+}
+
+fn press_button_p1(state: &mut [bool], button: &[usize]) {
+    for &i in button {
+        state[i] = !state[i];
+    }
+}
+
+fn shortest_solve_p1(problem: &Problem) -> Result<u64> {
+    let start = vec![false; problem.goal.len()];
+    let presses = |v: &Vec<bool>| {
+        let v = v.clone();
+        problem.buttons.iter().map(move |button| {
+            let mut w = v.clone();
+            press_button_p1(&mut w, button);
+            (w, 1)
+        })
+    };
+
+    search::dijkstra([start], presses, |v| *v == problem.goal)
+        .cost()
+        .ok_or_else(|| Error::other(format!("no button presses reach the goal of {problem:?}")))
+}
+
+fn shortest_solve_p2(problem: &Problem) -> Result<u64> {
+    let mut vars = ProblemVariables::new();
+
+    let mut button_vars = vec![];
+    for _button in &problem.buttons {
+        button_vars.push(vars.add(variable().min(0).integer()));
+    }
+
+    let mut model = vars
+        .minimise(button_vars.iter().sum::<good_lp::Expression>())
+        .using(default_solver);
+
+    let mut exprs = vec![0.into_expression(); problem.joltage.len()];
+    for (i, button) in problem.buttons.iter().enumerate() {
+        for &x in button {
+            exprs[x] += button_vars[i];
+        }
+    }
+    for (e, &j) in exprs.into_iter().zip(problem.joltage.iter()) {
+        model.add_constraint(e.eq(j as f64));
+    }
+
+    let solution = model
+        .solve()
+        .map_err(|e| Error::other(format!("failed to solve {problem:?}: {e}")))?;
+    let mut sum = 0.0;
+    for var in button_vars {
+        sum += solution.value(var);
+    }
+
+    crate::verbose!("{sum}");
+    Ok(sum.round() as u64)
+}
+
+pub fn part1(problems: &[Problem]) -> Result<Answer> {
+    let mut total = 0;
+    for problem in problems {
+        total += shortest_solve_p1(problem)?;
+    }
+    Ok(total.into())
+}
+
+pub fn part2(problems: &[Problem]) -> Result<Answer> {
+    let mut total = 0;
+    for problem in problems {
+        total += shortest_solve_p2(problem)?;
+    }
+    Ok(total.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<Problem>;
+
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Factory"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![example!("day10.txt").expect(1, 7).expect(2, 33)]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Example, example};
+
+fn paths_from<'a>(
+    source: &'a str,
+    target: &str,
+    adjacency: &'a HashMap<String, Vec<String>>,
+    memo: &mut HashMap<&'a str, u64>,
+) -> u64 {
+    if let Some(n) = memo.get(source) {
+        return *n;
+    }
+
+    if source == target {
+        return 1;
+    }
+
+    let mut n = 0;
+    let Some(neighbors) = adjacency.get(source) else {
+        return 0;
+    };
+    for v in neighbors {
+        n += paths_from(v, target, adjacency, memo);
+    }
+    memo.insert(source, n);
+
+    n
+}
+
+/// the devices each device's outputs are connected to
+pub fn parse(input: &str) -> Result<HashMap<String, Vec<String>>> {
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        let (node, neighbors) = line
+            .split_once(": ")
+            .ok_or_else(|| Error::missing(": ").with_line(i + 1).with_text(line))?;
+        let next = adjacency.entry(node.to_string()).or_default();
+        for n in neighbors.split(" ") {
+            next.push(n.to_string());
+        }
+    }
+    Ok(adjacency)
+}
+
+pub fn part1(adjacency: &HashMap<String, Vec<String>>) -> Result<Answer> {
+    let mut memo = HashMap::new();
+    let n = paths_from("you", "out", adjacency, &mut memo);
+    Ok(n.into())
+}
+
+pub fn part2(adjacency: &HashMap<String, Vec<String>>) -> Result<Answer> {
+    let mut n = 0;
+    n += paths_from("svr", "dac", adjacency, &mut HashMap::new())
+        * paths_from("dac", "fft", adjacency, &mut HashMap::new())
+        * paths_from("fft", "out", adjacency, &mut HashMap::new());
+
+    n += paths_from("svr", "fft", adjacency, &mut HashMap::new())
+        * paths_from("fft", "dac", adjacency, &mut HashMap::new())
+        * paths_from("dac", "out", adjacency, &mut HashMap::new());
+    Ok(n.into())
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Reactor"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            example!("day11.txt").expect(1, 5),
+            example!("day11-2.txt").expect(2, 2),
+        ]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,3 @@
+crate::solution::register_days! {
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12,
+}
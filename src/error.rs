@@ -0,0 +1,154 @@
+use std::fmt;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(std::io::Error),
+    ParseInt(std::num::ParseIntError),
+    /// a character or token the parser doesn't know what to do with
+    Unexpected,
+    /// a line of a grid whose length doesn't match the lines before it
+    RaggedRow {
+        expected: usize,
+        found: usize,
+    },
+    /// a separator that should split the input (or a line) in two is missing
+    MissingDelimiter(&'static str),
+    Other(String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Io(e) => write!(f, "{e}"),
+            ErrorKind::ParseInt(e) => write!(f, "invalid integer ({e})"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+            ErrorKind::RaggedRow { expected, found } => {
+                write!(f, "expected a row of length {expected}, found {found}")
+            }
+            ErrorKind::MissingDelimiter(d) => write!(f, "missing delimiter {d:?}"),
+            ErrorKind::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+/// An error raised while reading, parsing or solving a puzzle input.
+///
+/// Location information is optional and gets filled in as the error travels
+/// outwards: the parser that notices a problem usually knows the column and
+/// offending text, the day's code knows the line, and the runner knows the day.
+/// Lines and columns are 1-based.
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub day: Option<u32>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: Option<String>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            day: None,
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn unexpected(text: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Unexpected).with_text(text)
+    }
+
+    pub fn missing(delimiter: &'static str) -> Self {
+        Self::new(ErrorKind::MissingDelimiter(delimiter))
+    }
+
+    pub fn other(msg: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Other(msg.into()))
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// sets the offending text, unless a more specific one was already recorded
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        if self.text.is_none() {
+            self.text = Some(text.into());
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = vec![];
+        if let Some(day) = self.day {
+            location.push(format!("day {day}"));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {line}"));
+        }
+        if let Some(column) = self.column {
+            location.push(format!("column {column}"));
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        write!(f, "{}", self.kind)?;
+        if let Some(text) = &self.text {
+            write!(f, ": {text:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            ErrorKind::ParseInt(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorKind::Io(e))
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Self::new(ErrorKind::ParseInt(e))
+    }
+}
+
+/// Attaches location information to the error of a `Result`.
+pub trait ResultExt<T> {
+    /// records the 1-based line number and the line's text
+    fn on_line(self, line_no: usize, line: &str) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultExt<T> for std::result::Result<T, E> {
+    fn on_line(self, line_no: usize, line: &str) -> Result<T> {
+        self.map_err(|e| e.into().with_line(line_no).with_text(line))
+    }
+}
@@ -0,0 +1,13 @@
+//! Advent of Code 2025: the solutions for every day, plus the grid, vector and
+//! parsing helpers in [`util`] they're built on.
+
+pub mod answers;
+pub mod days;
+pub mod error;
+pub mod pool;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod util;
+pub mod verbose;
+pub mod watch;
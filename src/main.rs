@@ -1,60 +1,350 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
+use std::process::ExitCode;
+use std::time::Duration;
 
-pub mod util;
+use aoc2025::days::SOLUTIONS;
+use aoc2025::solution::Solution;
+use aoc2025::{answers, runner, scaffold, solution, util, verbose, watch};
 
-fn main() {
-    let day = std::env::args()
-        .nth(1)
-        .expect("Enter a day. e.g. \"1.1\" for day 1, part 1.");
-    let filename = std::env::args().nth(2);
-    match day.as_ref() {
-        "1.1" => day01::part1(&filename.unwrap_or("input/day01.txt".to_string())),
-        "1.2" => day01::part2(&filename.unwrap_or("input/day01.txt".to_string())),
+const USAGE: &str = "\
+Usage: AdventOfCode2025 [-v] [--format text|json] [-j N] [--timeout SECS] <selection> [input]
+       AdventOfCode2025 [-v] bench [-n RUNS] [--warmup N] <D.P> [input]
+       AdventOfCode2025 [-v] [-j N] [--timeout SECS] verify [--record] [--answers FILE] [selection]
+       AdventOfCode2025 [-v] watch <D.P> [input]
+       AdventOfCode2025 new <day> [title]
 
-        "2.1" => day02::part1(&filename.unwrap_or("input/day02.txt".to_string())),
-        "2.2" => day02::part2(&filename.unwrap_or("input/day02.txt".to_string())),
+Selections:
+  D.P       part P of day D, e.g. \"1.1\" for day 1, part 1
+  D         both parts of day D
+  all       every available day
+  A..B      days A to B (exclusive), also A..=B, A.. and ..B
+  list      show the available days
 
-        "3.1" => day03::part1(&filename.unwrap_or("input/day03.txt".to_string())),
-        "3.2" => day03::part2(&filename.unwrap_or("input/day03.txt".to_string())),
+The input defaults to input/dayNN.txt, and can only be given when a single day is selected.
+Use - as the input to read it from stdin.
+With --format json, every run is printed as a JSON object on its own line.
 
-        "4.1" => day04::part1(&filename.unwrap_or("input/day04.txt".to_string())),
-        "4.2" => day04::part2(&filename.unwrap_or("input/day04.txt".to_string())),
+Days are run in parallel on -j/--jobs threads, one per CPU by default; use -j 1
+for the most reliable timings. A day still running after --timeout seconds (60
+by default, 0 for no limit) is reported as timed out.";
 
-        "5.1" => day05::part1(&filename.unwrap_or("input/day05.txt".to_string())),
-        "5.2" => day05::part2(&filename.unwrap_or("input/day05.txt".to_string())),
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
-        "6.1" => day06::part1(&filename.unwrap_or("input/day06.txt".to_string())),
-        "6.2" => day06::part2(&filename.unwrap_or("input/day06.txt".to_string())),
+fn list_days() {
+    println!("Available days:");
+    for s in SOLUTIONS {
+        println!("  {:>2}: {}", s.day(), s.title());
+    }
+}
+
+/// removes a flag from the arguments, returning whether it was present
+fn take_flag(args: &mut Vec<String>, names: &[&str]) -> bool {
+    match args.iter().position(|a| names.contains(&a.as_str())) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+/// removes an option and its value from the arguments
+fn take_option(args: &mut Vec<String>, names: &[&str]) -> Option<String> {
+    let i = args.iter().position(|a| names.contains(&a.as_str()))?;
+    args.remove(i);
+    (i < args.len()).then(|| args.remove(i))
+}
+
+fn bench(mut args: Vec<String>) -> ExitCode {
+    let runs = take_option(&mut args, &["-n", "--runs"]).map(|n| n.parse::<usize>());
+    let warmup = take_option(&mut args, &["--warmup"]).map(|n| n.parse::<usize>());
+    let (Ok(runs), Ok(warmup)) = (runs.unwrap_or(Ok(100)), warmup.unwrap_or(Ok(3))) else {
+        eprintln!("error: expected a number of runs");
+        return ExitCode::FAILURE;
+    };
+    let mut args = args.into_iter();
+
+    let jobs = match args.next().as_deref().map(runner::select) {
+        Some(Ok(jobs)) => jobs,
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let [job] = jobs.as_slice() else {
+        eprintln!("error: bench expects a single part, e.g. \"8.1\"");
+        return ExitCode::FAILURE;
+    };
+    let input = args.next().unwrap_or_else(|| job.solution.default_input());
+
+    match runner::read_input(*job, &input)
+        .and_then(|input| runner::bench(*job, &input, warmup, runs))
+    {
+        Ok(bench) => {
+            runner::print_bench(*job, &bench);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn verify(mut args: Vec<String>, threads: usize, timeout: Option<Duration>) -> ExitCode {
+    let record = take_flag(&mut args, &["--record"]);
+    let path =
+        take_option(&mut args, &["--answers"]).unwrap_or_else(|| answers::DEFAULT_PATH.to_string());
+    let spec = args.first().map_or("all", String::as_str);
+
+    let (jobs, mut answers) = match (runner::select(spec), answers::Answers::load(&path)) {
+        (Ok(jobs), Ok(answers)) => (jobs, answers),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut counts = [0; 5];
+    let mut failed = false;
+    let groups = runner::group_by_day(&jobs);
+    let results = runner::run_parallel(&groups, |s| s.default_input(), None, threads, timeout);
+    for result in results {
+        let verdict = answers::check(&result, &mut answers, record);
+        failed |= verdict.is_failure();
+
+        let label = format!("{:>2}.{}", result.day, result.part);
+        match &verdict {
+            answers::Verdict::Pass => {
+                counts[0] += 1;
+                println!("{label}  pass");
+            }
+            answers::Verdict::Fail { expected, actual } => {
+                counts[1] += 1;
+                println!("{label}  FAIL");
+                println!("        expected: {expected}");
+                println!("        actual:   {actual}");
+            }
+            answers::Verdict::Error => {
+                counts[1] += 1;
+                match &result.outcome {
+                    runner::Outcome::Failed(e) => println!("{label}  ERROR    {e}"),
+                    runner::Outcome::TimedOut(t) => {
+                        println!("{label}  ERROR    timed out after {t:.0?}")
+                    }
+                    _ => {}
+                }
+            }
+            answers::Verdict::Missing(actual) => {
+                counts[2] += 1;
+                println!("{label}  missing  {actual}");
+            }
+            answers::Verdict::Recorded(actual) => {
+                counts[3] += 1;
+                println!("{label}  recorded {actual}");
+            }
+            answers::Verdict::Skipped => {
+                counts[4] += 1;
+                println!("{label}  skipped");
+            }
+        }
+    }
+
+    let [pass, fail, missing, recorded, skipped] = counts;
+    println!();
+    println!(
+        "{pass} passed, {fail} failed, {missing} missing, {recorded} recorded, {skipped} skipped"
+    );
+
+    if recorded > 0
+        && let Err(e) = answers.save(&path)
+    {
+        eprintln!("error: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
 
-        "7.1" => day07::part1(&filename.unwrap_or("input/day07.txt".to_string())),
-        "7.2" => day07::part2(&filename.unwrap_or("input/day07.txt".to_string())),
+fn new_day(args: Vec<String>) -> ExitCode {
+    let Some(day) = args.first().and_then(|d| d.parse::<u32>().ok()) else {
+        eprintln!("error: expected a day number, e.g. `new 13`");
+        return ExitCode::FAILURE;
+    };
+    let title = args.get(1).cloned().unwrap_or_else(|| format!("Day {day}"));
 
-        "8.1" => day08::part1(&filename.unwrap_or("input/day08.txt".to_string())),
-        "8.2" => day08::part2(&filename.unwrap_or("input/day08.txt".to_string())),
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold::new_day(root, day, &title) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
 
-        "9.1" => day09::part1(&filename.unwrap_or("input/day09.txt".to_string())),
-        "9.2" => day09::part2(&filename.unwrap_or("input/day09.txt".to_string())),
+fn watch(args: Vec<String>) -> ExitCode {
+    let jobs = match args.first().map(|spec| runner::select(spec)) {
+        Some(Ok(jobs)) => jobs,
+        Some(Err(e)) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let [job] = jobs.as_slice() else {
+        eprintln!("error: watch expects a single part, e.g. \"7.2\"");
+        return ExitCode::FAILURE;
+    };
+    let input = args
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| job.solution.default_input());
+    if input == "-" {
+        eprintln!("error: watch can't read its input from stdin");
+        return ExitCode::FAILURE;
+    }
 
-        "10.1" => day10::part1(&filename.unwrap_or("input/day10.txt".to_string())),
-        "10.2" => day10::part2(&filename.unwrap_or("input/day10.txt".to_string())),
+    watch::watch(
+        *job,
+        &input,
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+    )
+}
 
-        "11.1" => day11::part1(&filename.unwrap_or("input/day11.txt".to_string())),
-        "11.2" => day11::part2(&filename.unwrap_or("input/day11.txt".to_string())),
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if take_flag(&mut args, &["-v", "--verbose"]) {
+        verbose::set_enabled(true);
+    }
+
+    let json = match take_option(&mut args, &["--format"]).as_deref() {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(f) => {
+            eprintln!("error: unknown format {f:?}, expected \"text\" or \"json\"");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let threads = match take_option(&mut args, &["-j", "--jobs"]).map(|n| n.parse::<usize>()) {
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("error: expected a positive number of jobs");
+            return ExitCode::FAILURE;
+        }
+    };
+    let timeout = match take_option(&mut args, &["--timeout"]).map(|n| n.parse::<u64>()) {
+        None => Some(DEFAULT_TIMEOUT),
+        Some(Ok(0)) => None,
+        Some(Ok(secs)) => Some(Duration::from_secs(secs)),
+        Some(Err(_)) => {
+            eprintln!("error: expected a timeout in seconds");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.first().map(String::as_str) {
+        Some("bench") => return bench(args.split_off(1)),
+        Some("verify") => return verify(args.split_off(1), threads, timeout),
+        Some("watch") => return watch(args.split_off(1)),
+        Some("new") => return new_day(args.split_off(1)),
+        _ => {}
+    }
+    let mut args = args.into_iter();
 
-        "12.1" => day12::part1(&filename.unwrap_or("input/day12.txt".to_string())),
-        "12.2" => day12::part2(&filename.unwrap_or("input/day12.txt".to_string())),
+    let Some(spec) = args.next() else {
+        println!("{USAGE}");
+        println!();
+        list_days();
+        return ExitCode::SUCCESS;
+    };
+    let filename = args.next();
+
+    if spec == "list" {
+        list_days();
+        return ExitCode::SUCCESS;
+    }
+
+    let jobs = match runner::select(&spec) {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            eprintln!("error: {e}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if filename.is_some()
+        && jobs
+            .iter()
+            .any(|j| j.solution.day() != jobs[0].solution.day())
+    {
+        eprintln!("error: an input file can only be given for a single day");
+        return ExitCode::FAILURE;
+    }
+    let input_for =
+        |solution: &dyn Solution| filename.clone().unwrap_or_else(|| solution.default_input());
+
+    // a single part just prints its answer, so it can be used in scripts
+    if let [job] = jobs.as_slice()
+        && !json
+    {
+        return match runner::read_input(*job, &input_for(job.solution))
+            .and_then(|input| solution::solve(job.solution.day(), job.part, &input))
+        {
+            Ok(answer) => {
+                println!("{answer}");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    // stdin can only be read once, so share it between the parts
+    let stdin = match filename.as_deref() {
+        Some("-") => match util::read_input("-") {
+            Ok(input) => Some(input),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => None,
+    };
+
+    let groups = runner::group_by_day(&jobs);
+    let results = runner::run_parallel(&groups, input_for, stdin.as_deref(), threads, timeout);
+    if json {
+        for r in &results {
+            println!("{}", r.to_json());
+        }
+    } else {
+        runner::print_table(&results);
+    }
 
-        _ => println!("Part {day} not available."),
+    if results.iter().any(|r| r.outcome.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
     }
 }
@@ -0,0 +1,138 @@
+//! Runs independent tasks on a bounded number of threads, giving up on any
+//! task that takes longer than a timeout.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status<T> {
+    Done(T),
+    /// the task panicked, with the panic message if there was one
+    Panicked(String),
+    /// the task was still running when the timeout ran out
+    TimedOut,
+}
+
+/// Runs every task on its own thread, with at most `threads` of them running
+/// at once, and returns their results in the same order as `tasks`.
+///
+/// Threads can't be cancelled, so a task that times out is left running in
+/// the background and no longer counts towards `threads`. Its result is
+/// discarded if it ever finishes.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Vec<Status<T>> {
+    let threads = threads.max(1);
+    let mut results: Vec<Option<Status<T>>> = tasks.iter().map(|_| None).collect();
+    let mut pending = tasks.into_iter().enumerate();
+    // the index and start time of every task that is running and not yet timed out
+    let mut running: Vec<(usize, Instant)> = vec![];
+    let (tx, rx) = mpsc::channel();
+
+    loop {
+        while running.len() < threads
+            && let Some((i, task)) = pending.next()
+        {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let status = match panic::catch_unwind(AssertUnwindSafe(task)) {
+                    Ok(result) => Status::Done(result),
+                    Err(payload) => Status::Panicked(panic_message(payload.as_ref())),
+                };
+                // the receiver is gone if everything else finished first, which is fine
+                let _ = tx.send((i, status));
+            });
+            running.push((i, Instant::now()));
+        }
+
+        if running.is_empty() {
+            break;
+        }
+
+        let received = match timeout {
+            Some(timeout) => {
+                let deadline = running.iter().map(|&(_, start)| start + timeout).min();
+                let wait =
+                    deadline.map_or(timeout, |d| d.saturating_duration_since(Instant::now()));
+                rx.recv_timeout(wait)
+            }
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match received {
+            Ok((i, status)) => {
+                // results of tasks that already timed out are ignored
+                if let Some(pos) = running.iter().position(|&(j, _)| j == i) {
+                    running.remove(pos);
+                    results[i] = Some(status);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                running.retain(|&(i, start)| {
+                    let expired = timeout.is_some_and(|t| now.duration_since(start) >= t);
+                    if expired {
+                        results[i] = Some(Status::TimedOut);
+                    }
+                    !expired
+                });
+            }
+            // `tx` is still alive, so this can't happen
+            Err(RecvTimeoutError::Disconnected) => unreachable!(),
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|r| r.expect("every task either finished or timed out"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_and_skips_runaway_tasks() {
+        let tasks: Vec<Task<u32>> = vec![
+            Box::new(|| {
+                thread::sleep(Duration::from_millis(50));
+                1
+            }),
+            Box::new(|| {
+                loop {
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }),
+            Box::new(|| panic!("oops")),
+            Box::new(|| 4),
+        ];
+
+        let results = run(tasks, 2, Some(Duration::from_millis(200)));
+        assert_eq!(
+            results,
+            vec![
+                Status::Done(1),
+                Status::TimedOut,
+                Status::Panicked("oops".to_string()),
+                Status::Done(4),
+            ]
+        );
+    }
+}
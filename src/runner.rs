@@ -0,0 +1,474 @@
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::pool;
+use crate::solution::{self, Answer, Solution};
+use crate::util::{self, stats};
+
+/// A single part of a single day, ready to be run.
+#[derive(Clone, Copy)]
+pub struct Job {
+    pub solution: &'static dyn Solution,
+    pub part: u32,
+}
+
+pub enum Outcome {
+    Solved(Answer),
+    Failed(Error),
+    /// the input file doesn't exist, so the part wasn't run
+    MissingInput,
+    /// the day was still running after the given time, see [`run_parallel`]
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed(_) | Outcome::TimedOut(_))
+    }
+}
+
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub outcome: Outcome,
+    /// the time taken to parse the input, or `None` if the parsed input was
+    /// shared with an earlier part of the same day
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+impl RunResult {
+    /// Renders the result as a single-line JSON object with the fields `day`,
+    /// `part`, `input`, `status` (`solved`, `unimplemented`, `failed`,
+    /// `missing_input` or `timed_out`), `answer` (a number, a string or `null`), `parse_ns`
+    /// (`null` if the parsed input was shared with an earlier part), `solve_ns`
+    /// and `error` (a message or `null`).
+    pub fn to_json(&self) -> String {
+        let null = || "null".to_string();
+        let (status, answer, error) = match &self.outcome {
+            Outcome::Solved(Answer::Int(n)) => ("solved", n.to_string(), null()),
+            Outcome::Solved(Answer::Str(s)) => ("solved", json_string(s), null()),
+            Outcome::Solved(Answer::Unimplemented) => ("unimplemented", null(), null()),
+            Outcome::Failed(e) => ("failed", null(), json_string(&e.to_string())),
+            Outcome::MissingInput => ("missing_input", null(), null()),
+            Outcome::TimedOut(_) => ("timed_out", null(), null()),
+        };
+
+        format!(
+            r#"{{"day":{},"part":{},"input":{},"status":"{status}","answer":{answer},"parse_ns":{},"solve_ns":{},"error":{error}}}"#,
+            self.day,
+            self.part,
+            json_string(&self.input),
+            self.parse.map_or_else(null, |d| d.as_nanos().to_string()),
+            self.solve.as_nanos(),
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Parses a selection of days and parts:
+/// - `all`: every registered day, both parts
+/// - `D`: both parts of day `D`
+/// - `D.P`: part `P` of day `D`
+/// - `A..B`, `A..=B`, `A..`, `..B`: ranges of days, with the same meaning as in Rust
+pub fn select(spec: &str) -> Result<Vec<Job>> {
+    let invalid = || Error::other("invalid selection").with_text(spec);
+    let parse_day = |s: &str| s.parse::<u32>().map_err(|_| invalid());
+
+    let mut days = vec![];
+    let mut part = None;
+    if spec == "all" {
+        days.extend(crate::days::SOLUTIONS.iter().map(|s| s.day()));
+    } else if let Some((a, b)) = spec.split_once("..") {
+        let start = if a.is_empty() { 0 } else { parse_day(a)? };
+        let end = match b.strip_prefix('=') {
+            Some(b) => Bound::Included(parse_day(b)?),
+            None if b.is_empty() => Bound::Unbounded,
+            None => Bound::Excluded(parse_day(b)?),
+        };
+        let range = (Bound::Included(start), end);
+        days.extend(
+            crate::days::SOLUTIONS
+                .iter()
+                .map(|s| s.day())
+                .filter(|d| range.contains(d)),
+        );
+    } else if let Some((d, p)) = spec.split_once('.') {
+        days.push(parse_day(d)?);
+        part = Some(p.parse::<u32>().map_err(|_| invalid())?);
+    } else {
+        days.push(parse_day(spec)?);
+    }
+    if days.is_empty() {
+        return Err(Error::other("no days selected").with_text(spec));
+    }
+
+    let mut jobs = vec![];
+    for day in days {
+        let solution = solution::find(day)
+            .ok_or_else(|| Error::other(format!("day {day} is not available")))?;
+        match part {
+            Some(part @ 1..=2) => jobs.push(Job { solution, part }),
+            Some(part) => return Err(Error::other(format!("part {part} is not available"))),
+            None => {
+                jobs.push(Job { solution, part: 1 });
+                jobs.push(Job { solution, part: 2 });
+            }
+        }
+    }
+    Ok(jobs)
+}
+
+/// reads the input file (or stdin for `-`), tagging errors with the job's day
+pub fn read_input(job: Job, path: &str) -> Result<String> {
+    util::read_input(path).map_err(|e| e.with_day(job.solution.day()))
+}
+
+/// Splits jobs into runs of consecutive parts of the same day, which can share their parsed input.
+pub fn group_by_day(jobs: &[Job]) -> Vec<(&'static dyn Solution, Vec<u32>)> {
+    let mut groups: Vec<(&'static dyn Solution, Vec<u32>)> = vec![];
+    for job in jobs {
+        match groups.last_mut() {
+            Some((solution, parts)) if solution.day() == job.solution.day() => parts.push(job.part),
+            _ => groups.push((job.solution, vec![job.part])),
+        }
+    }
+    groups
+}
+
+/// Reads the input and runs the part on it. The time taken to read the input
+/// isn't included in the result.
+pub fn run(job: Job, path: &str) -> RunResult {
+    let mut results = run_day(job.solution, &[job.part], path);
+    results.remove(0)
+}
+
+/// Reads the input and runs the given parts of a day on it, see [`run_parts`].
+pub fn run_day(solution: &'static dyn Solution, parts: &[u32], path: &str) -> Vec<RunResult> {
+    let result = |part, outcome| RunResult {
+        day: solution.day(),
+        part,
+        input: path.to_string(),
+        outcome,
+        parse: None,
+        solve: Duration::ZERO,
+    };
+
+    match util::read_input(path).map_err(|e| e.with_day(solution.day())) {
+        Ok(input) => run_parts(solution, parts, path, &input),
+        Err(Error {
+            kind: ErrorKind::Io(e),
+            ..
+        }) if e.kind() == std::io::ErrorKind::NotFound => parts
+            .iter()
+            .map(|&part| result(part, Outcome::MissingInput))
+            .collect(),
+        Err(e) => {
+            let mut results = vec![result(parts[0], Outcome::Failed(e))];
+            results.extend(parts[1..].iter().map(|&part| {
+                let e = Error::other(format!("couldn't read {path}")).with_day(solution.day());
+                result(part, Outcome::Failed(e))
+            }));
+            results
+        }
+    }
+}
+
+/// Runs the given parts of a day on input text that has already been read
+/// from `path`. The input is parsed once and shared between the parts, so only
+/// the first result carries the parse time.
+pub fn run_parts(
+    solution: &'static dyn Solution,
+    parts: &[u32],
+    path: &str,
+    input: &str,
+) -> Vec<RunResult> {
+    let start = Instant::now();
+    let (parsed, mut error) = match solution.parse(input) {
+        Ok(parsed) => (Some(parsed), None),
+        Err(e) => (None, Some(e)),
+    };
+    let mut parse = Some(start.elapsed());
+
+    let mut results = vec![];
+    for &part in parts {
+        let start = Instant::now();
+        let outcome = match &parsed {
+            Some(parsed) => match solution.solve(part, parsed) {
+                Ok(answer) => Outcome::Solved(answer),
+                Err(e) => Outcome::Failed(e),
+            },
+            // the parse error itself goes with the first part
+            None => Outcome::Failed(error.take().unwrap_or_else(|| {
+                Error::other("the input couldn't be parsed").with_day(solution.day())
+            })),
+        };
+
+        results.push(RunResult {
+            day: solution.day(),
+            part,
+            input: path.to_string(),
+            outcome,
+            parse: parse.take(),
+            solve: start.elapsed(),
+        });
+    }
+    results
+}
+
+/// Runs each day's parts with [`run_day`] (or [`run_parts`] if `stdin` is
+/// given), spreading the days over `threads` threads. The results come back in
+/// the same order as `groups`, however the days are scheduled. A day that's
+/// still running after `timeout` is abandoned, and its parts are reported as
+/// [`Outcome::TimedOut`].
+pub fn run_parallel(
+    groups: &[(&'static dyn Solution, Vec<u32>)],
+    input_for: impl Fn(&dyn Solution) -> String,
+    stdin: Option<&str>,
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let stdin: Option<Arc<str>> = stdin.map(Arc::from);
+    let tasks: Vec<pool::Task<Vec<RunResult>>> = groups
+        .iter()
+        .map(|(solution, parts)| {
+            let (solution, parts) = (*solution, parts.clone());
+            let (path, stdin) = (input_for(solution), stdin.clone());
+            Box::new(move || match stdin {
+                Some(input) => run_parts(solution, &parts, &path, &input),
+                None => run_day(solution, &parts, &path),
+            }) as pool::Task<_>
+        })
+        .collect();
+
+    let mut results = vec![];
+    for ((solution, parts), status) in groups.iter().zip(pool::run(tasks, threads, timeout)) {
+        let panic = match status {
+            pool::Status::Done(r) => {
+                results.extend(r);
+                continue;
+            }
+            pool::Status::TimedOut => None,
+            pool::Status::Panicked(msg) => Some(msg),
+        };
+        for &part in parts {
+            let outcome = match &panic {
+                None => Outcome::TimedOut(timeout.unwrap_or_default()),
+                Some(msg) => Outcome::Failed(
+                    Error::other(format!("panicked: {msg}")).with_day(solution.day()),
+                ),
+            };
+            results.push(RunResult {
+                day: solution.day(),
+                part,
+                input: match stdin {
+                    Some(_) => "-".to_string(),
+                    None => input_for(*solution),
+                },
+                outcome,
+                parse: None,
+                solve: Duration::ZERO,
+            });
+        }
+    }
+    results
+}
+
+pub fn print_table(results: &[RunResult]) {
+    let time = |d: Option<Duration>| d.map_or_else(String::new, |d| format!("{d:.2?}"));
+    let rows: Vec<[String; 5]> = results
+        .iter()
+        .map(|r| {
+            let (answer, parse, solve) = match &r.outcome {
+                Outcome::Solved(answer) => (answer.to_string(), time(r.parse), time(Some(r.solve))),
+                Outcome::Failed(_) => ("error".to_string(), time(r.parse), time(Some(r.solve))),
+                Outcome::MissingInput => (format!("missing {}", r.input), "-".into(), "-".into()),
+                Outcome::TimedOut(t) => {
+                    (format!("timed out after {t:.0?}"), "-".into(), "-".into())
+                }
+            };
+            [r.day.to_string(), r.part.to_string(), answer, parse, solve]
+        })
+        .collect();
+
+    let header = ["Day", "Part", "Answer", "Parse", "Solve"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let [d, p, a, t, s] = widths;
+    println!(
+        "{:>d$}  {:>p$}  {:<a$}  {:>t$}  {:>s$}",
+        header[0], header[1], header[2], header[3], header[4]
+    );
+    println!("{}", "-".repeat(d + p + a + t + s + 8));
+    for [day, part, answer, parse, solve] in &rows {
+        println!("{day:>d$}  {part:>p$}  {answer:<a$}  {parse:>t$}  {solve:>s$}");
+    }
+
+    for r in results {
+        if let Outcome::Failed(e) = &r.outcome {
+            eprintln!("error: {e}");
+        }
+    }
+}
+
+/// The measurements taken by [`bench`], one per run and phase.
+pub struct Bench {
+    pub answer: Answer,
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+/// Runs a part `warmup` times without measuring it, then `runs` more times,
+/// timing the parsing and solving of each measured run separately.
+pub fn bench(job: Job, input: &str, warmup: usize, runs: usize) -> Result<Bench> {
+    let mut answer = None;
+    for _ in 0..warmup {
+        answer = Some(job.solution.part(job.part, input)?);
+    }
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = job.solution.parse(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        let a = job.solution.solve(job.part, &parsed)?;
+        solve.push(start.elapsed());
+        answer = Some(a);
+    }
+
+    let answer = answer.ok_or_else(|| Error::other("nothing was run"))?;
+    Ok(Bench {
+        answer,
+        parse,
+        solve,
+    })
+}
+
+pub fn print_bench(job: Job, bench: &Bench) {
+    println!(
+        "day {} part {}: {} ({} runs)",
+        job.solution.day(),
+        job.part,
+        bench.answer,
+        bench.solve.len()
+    );
+    let total: Vec<Duration> = bench
+        .parse
+        .iter()
+        .zip(&bench.solve)
+        .map(|(p, s)| *p + *s)
+        .collect();
+    for (phase, samples) in [
+        ("parse", &bench.parse),
+        ("solve", &bench.solve),
+        ("total", &total),
+    ] {
+        print_summary(phase, samples);
+    }
+}
+
+fn print_summary(phase: &str, samples: &[Duration]) {
+    let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+    let Some(s) = stats::Summary::new(&secs) else {
+        return;
+    };
+    let fmt = |secs: f64| format!("{:.2?}", Duration::from_secs_f64(secs));
+
+    println!("{phase}:");
+    println!("  mean    {:>10} ± {}", fmt(s.mean), fmt(s.std_dev));
+    println!("  median  {:>10}", fmt(s.median));
+    println!("  min     {:>10}", fmt(s.min));
+    println!("  max     {:>10}", fmt(s.max));
+    println!(
+        "  p5 {}  p25 {}  p75 {}  p95 {}  p99 {}",
+        fmt(s.p5),
+        fmt(s.p25),
+        fmt(s.p75),
+        fmt(s.p95),
+        fmt(s.p99)
+    );
+    if s.outliers > 0 {
+        println!(
+            "  {} outliers ({:.1}%)",
+            s.outliers,
+            100.0 * s.outliers as f64 / s.n as f64
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_record() {
+        let result = RunResult {
+            day: 6,
+            part: 2,
+            input: "input/\"day06\".txt".to_string(),
+            outcome: Outcome::Solved(Answer::Str("a\nb".to_string())),
+            parse: None,
+            solve: Duration::from_micros(3),
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":6,"part":2,"input":"input/\"day06\".txt","status":"solved","answer":"a\nb","parse_ns":null,"solve_ns":3000,"error":null}"#
+        );
+    }
+
+    #[test]
+    fn empty_selections() {
+        for spec in ["..0", "0..1", "5..5", "3..=2"] {
+            let Err(e) = select(spec) else {
+                panic!("{spec:?} should select nothing");
+            };
+            assert_eq!(e.to_string(), format!("no days selected: {spec:?}"));
+        }
+        assert_eq!(select("..2").unwrap().len(), 2);
+        assert_eq!(select("12..").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parts_share_parsed_input() {
+        let day01 = solution::find(1).unwrap();
+        let results = run_parts(day01, &[1, 2], "-", "L68\nL30\nR48\n");
+        assert!(results[0].parse.is_some());
+        assert!(results[1].parse.is_none());
+
+        let results = run_parts(day01, &[1, 2], "-", "L68\nX30\n");
+        let [Outcome::Failed(first), Outcome::Failed(_)] =
+            [&results[0].outcome, &results[1].outcome]
+        else {
+            panic!("a parse error should fail both parts");
+        };
+        assert_eq!(first.line, Some(2));
+    }
+}
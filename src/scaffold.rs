@@ -0,0 +1,157 @@
+//! Generates the files for a new day and registers it in `src/days/mod.rs`.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+const TEMPLATE: &str = r#"use crate::error::Result;
+use crate::solution::{Answer, Example, example};
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+pub fn part1(_input: &[String]) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+pub fn part2(_input: &[String]) -> Result<Answer> {
+    Ok(Answer::Unimplemented)
+}
+
+pub struct Solver;
+
+impl crate::solution::Day for Solver {
+    type Input = Vec<String>;
+
+    fn day(&self) -> u32 {
+        {day}
+    }
+
+    fn title(&self) -> &'static str {
+        {title:?}
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        // paste the example into examples/day{day:02}.txt, then add its answers
+        // with `.expect(1, ...)` and `.expect(2, ...)`
+        vec![example!("day{day:02}.txt")]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
+"#;
+
+const REGISTRY: &str = "src/days/mod.rs";
+const REGISTER_MACRO: &str = "register_days! {";
+
+/// Writes `src/days/dayNN.rs`, an empty `examples/dayNN.txt` and `input/dayNN.txt`,
+/// and adds the module to the registry. `root` is the crate root. Existing
+/// example and input files are left alone, but an existing module is an error.
+///
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u32, title: &str) -> Result<Vec<PathBuf>> {
+    let module = format!("day{day:02}");
+    let source = root.join("src/days").join(format!("{module}.rs"));
+    if source.exists() {
+        return Err(Error::other(format!("{} already exists", source.display())));
+    }
+
+    let registry = root.join(REGISTRY);
+    let registered = register(&read(&registry)?, &module)?;
+
+    let mut written = vec![];
+    let code = TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{title:?}", &format!("{title:?}"));
+    write(&source, &code)?;
+    written.push(source);
+
+    for dir in ["examples", "input"] {
+        let path = root.join(dir).join(format!("{module}.txt"));
+        if !path.exists() {
+            std::fs::create_dir_all(root.join(dir)).map_err(|e| io_error(e, &path))?;
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    write(&registry, &registered)?;
+    written.push(registry);
+
+    Ok(written)
+}
+
+/// adds `module` to the `register_days!` invocation in `registry`, keeping the days sorted
+fn register(registry: &str, module: &str) -> Result<String> {
+    let missing = || Error::other(format!("no `{REGISTER_MACRO}` found in {REGISTRY}"));
+    let start = registry.find(REGISTER_MACRO).ok_or_else(missing)? + REGISTER_MACRO.len();
+    let len = registry[start..].find('}').ok_or_else(missing)?;
+
+    let mut days: Vec<&str> = registry[start..start + len]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|d| !d.is_empty())
+        .collect();
+    if days.contains(&module) {
+        return Err(Error::other(format!("{module} is already registered")));
+    }
+    days.push(module);
+    days.sort();
+
+    let mut list = String::from("\n");
+    let mut line = String::from("   ");
+    for day in days {
+        if line.len() + day.len() + 2 > 100 {
+            list.push_str(&line);
+            list.push('\n');
+            line = String::from("   ");
+        }
+        line.push_str(&format!(" {day},"));
+    }
+    list.push_str(&line);
+    list.push('\n');
+
+    Ok(format!(
+        "{}{list}{}",
+        &registry[..start],
+        &registry[start + len..]
+    ))
+}
+
+fn io_error(e: std::io::Error, path: &Path) -> Error {
+    Error::from(e).with_text(path.display().to_string())
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| io_error(e, path))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    std::fs::write(path, contents).map_err(|e| io_error(e, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let registry = "crate::solution::register_days! {\n    day01, day03,\n}\n";
+        assert_eq!(
+            register(registry, "day02").unwrap(),
+            "crate::solution::register_days! {\n    day01, day02, day03,\n}\n"
+        );
+        assert!(register(registry, "day03").is_err());
+    }
+}
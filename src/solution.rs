@@ -0,0 +1,236 @@
+use std::any::Any;
+
+use crate::error::{Error, Result};
+
+/// The result of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    Unimplemented,
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// Input that has been parsed by [`Solution::parse`]. Only the solution that
+/// produced it knows its type, so it can only be passed back to that solution.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// A single day's puzzle, split into parsing the input text and solving each
+/// part on the parsed input. Every `dayNN` module implements this for a unit
+/// struct `Solver`, which makes it a [`Solution`].
+pub trait Day: Sync {
+    /// the parsed input, shared between both parts
+    type Input: Send + Sync + 'static;
+
+    fn day(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    /// the puzzle's examples, see `example!`
+    fn examples(&self) -> Vec<Example> {
+        vec![]
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+/// The type-erased form of [`Day`], so the solutions can be collected into
+/// [`SOLUTIONS`](crate::days::SOLUTIONS) by the `register_days!` invocation in `days/mod.rs`.
+pub trait Solution: Sync {
+    fn day(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    fn examples(&self) -> Vec<Example>;
+
+    /// parses the input text, tagging any error with this day's number
+    fn parse(&self, input: &str) -> Result<Parsed>;
+
+    /// solves the given part on input returned by this solution's [`parse`](Solution::parse),
+    /// tagging any error with this day's number
+    fn solve(&self, part: u32, input: &Parsed) -> Result<Answer>;
+
+    /// parses the input text and solves the given part on it
+    fn part(&self, part: u32, input: &str) -> Result<Answer> {
+        self.solve(part, &self.parse(input)?)
+    }
+
+    /// the input file used when none is given on the command line
+    fn default_input(&self) -> String {
+        format!("input/day{:02}.txt", self.day())
+    }
+}
+
+impl<D: Day> Solution for D {
+    fn day(&self) -> u32 {
+        Day::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Day::title(self)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Day::examples(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        match Day::parse(self, input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.with_day(Day::day(self))),
+        }
+    }
+
+    fn solve(&self, part: u32, input: &Parsed) -> Result<Answer> {
+        let day = Day::day(self);
+        let input = input
+            .downcast_ref::<D::Input>()
+            .ok_or_else(|| Error::other("the input was parsed by a different day").with_day(day))?;
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => Err(Error::other(format!("there is no part {part}"))),
+        }
+        .map_err(|e| e.with_day(day))
+    }
+}
+
+/// An example input from a puzzle's description, along with the answers it should give.
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// relative to the crate root
+    pub path: &'static str,
+    pub input: &'static str,
+    /// the expected answers for part 1 and 2, if the example applies to that part
+    pub expected: [Option<Answer>; 2],
+}
+
+impl Example {
+    pub fn new(path: &'static str, input: &'static str) -> Self {
+        Self {
+            path,
+            input,
+            expected: [None, None],
+        }
+    }
+
+    pub fn expect(mut self, part: u32, answer: impl Into<Answer>) -> Self {
+        self.expected[part as usize - 1] = Some(answer.into());
+        self
+    }
+
+    pub fn expected(&self, part: u32) -> Option<&Answer> {
+        self.expected.get(part as usize - 1)?.as_ref()
+    }
+}
+
+/// Embeds `examples/<file>` as an [`Example`].
+macro_rules! example {
+    ($file:literal) => {
+        $crate::solution::Example::new(
+            concat!("examples/", $file),
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $file)),
+        )
+    };
+}
+
+pub(crate) use example;
+
+/// Runs every example of `solution` that has an expected answer, panicking on
+/// the first wrong answer or error.
+#[cfg(test)]
+pub fn check_examples(solution: &dyn Solution) {
+    for example in solution.examples() {
+        if example.expected.iter().all(Option::is_none) {
+            continue;
+        }
+        let parsed = solution
+            .parse(example.input)
+            .unwrap_or_else(|e| panic!("{}: {e}", example.path));
+        for part in 1..=2 {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+            match solution.solve(part, &parsed) {
+                Ok(actual) => assert_eq!(&actual, expected, "{} part {part}", example.path),
+                Err(e) => panic!("{} part {part}: {e}", example.path),
+            }
+        }
+    }
+}
+
+/// Declares the `dayNN` modules and builds the registry from them.
+/// Each module must export a unit struct `Solver` implementing [`Day`].
+/// This also generates a test per day that checks its examples.
+macro_rules! register_days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub static SOLUTIONS: &[&dyn $crate::solution::Solution] = &[$(&$day::Solver),*];
+
+        #[cfg(test)]
+        mod examples {
+            $(
+                #[test]
+                fn $day() {
+                    $crate::solution::check_examples(&super::$day::Solver);
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use register_days;
+
+pub fn find(day: u32) -> Option<&'static dyn Solution> {
+    crate::days::SOLUTIONS
+        .iter()
+        .copied()
+        .find(|s| s.day() == day)
+}
+
+/// Solves a part of a day on the given input text, without touching the filesystem.
+pub fn solve(day: u32, part: u32, input: &str) -> Result<Answer> {
+    find(day)
+        .ok_or_else(|| Error::other(format!("day {day} is not available")))?
+        .part(part, input)
+}
@@ -0,0 +1,373 @@
+//! Cellular automata: every generation, each cell's next value is worked out
+//! by a rule from the cell and its 8 neighbors, all at once. Works on a
+//! [`Grid`], where the cells outside are missing, or on a [`SparseGrid`],
+//! which grows as needed.
+
+use std::collections::HashSet;
+
+use super::{Grid, SparseGrid, Vec2, vec2};
+
+/// The 8 cells around the one a rule is applied to. Cells that are outside
+/// the grid, or empty in a sparse grid, are missing.
+pub struct Neighborhood<'a, T> {
+    /// row by row, with `None` for the cell in the middle
+    cells: [Option<&'a T>; 9],
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    fn new(mut get: impl FnMut(Vec2) -> Option<&'a T>) -> Self {
+        let mut cells = [None; 9];
+        for (i, cell) in cells.iter_mut().enumerate() {
+            let d = vec2(i as i32 % 3 - 1, i as i32 / 3 - 1);
+            if d != Vec2::ZERO {
+                *cell = get(d);
+            }
+        }
+        Self { cells }
+    }
+
+    /// the neighbor at `(dx, dy)` from the middle, where both are between -1 and 1
+    pub fn get(&self, dx: i32, dy: i32) -> Option<&'a T> {
+        assert!(
+            (-1..=1).contains(&dx) && (-1..=1).contains(&dy),
+            "({dx}, {dy}) isn't a neighbor"
+        );
+        self.cells[(dx + 1 + (dy + 1) * 3) as usize]
+    }
+
+    /// the neighbors that are there, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.cells.iter().flatten().copied()
+    }
+
+    /// the neighbors above, left, right and below, if they're there
+    pub fn orthogonal(&self) -> impl Iterator<Item = &'a T> + '_ {
+        [1, 3, 5, 7].into_iter().filter_map(|i| self.cells[i])
+    }
+
+    /// the number of neighbors `f` returns true for
+    pub fn count(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.iter().filter(|t| f(t)).count()
+    }
+}
+
+/// What happened during [`Automaton::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// the number of cells that changed in each generation
+    pub changes: Vec<usize>,
+    /// whether it stopped because a generation didn't change anything, rather
+    /// than because of the generation limit
+    pub fixpoint: bool,
+}
+
+impl Run {
+    pub fn generations(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// the number of changes over all generations
+    pub fn total(&self) -> usize {
+        self.changes.iter().sum()
+    }
+}
+
+pub trait Automaton {
+    /// works out the next generation, and returns how many cells changed
+    fn step(&mut self) -> usize;
+
+    /// the number of generations so far
+    fn generation(&self) -> usize;
+
+    /// Steps until a generation changes nothing, or until `limit` generations
+    /// have been run if there is one. The generation that changes nothing is
+    /// included in the result.
+    fn run(&mut self, limit: Option<usize>) -> Run {
+        let mut run = Run {
+            changes: vec![],
+            fixpoint: false,
+        };
+        while limit.is_none_or(|limit| run.changes.len() < limit) {
+            let changed = self.step();
+            run.changes.push(changed);
+            if changed == 0 {
+                run.fixpoint = true;
+                break;
+            }
+        }
+        run
+    }
+}
+
+/// An automaton over a [`Grid`], which swaps between two buffers instead of
+/// allocating each generation.
+pub struct GridAutomaton<T, R> {
+    grid: Grid<T>,
+    /// always the same as `grid` between steps
+    next: Grid<T>,
+    rule: R,
+    generation: usize,
+    /// the cells to work out in the next step, or `None` for all of them
+    worklist: Option<Vec<usize>>,
+    track_changes: bool,
+}
+
+impl<T, R> GridAutomaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&T, &Neighborhood<T>) -> T,
+{
+    /// `rule` gives the next value of a cell from its current value and its neighbors
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            next: grid.clone(),
+            grid,
+            rule,
+            generation: 0,
+            worklist: None,
+            track_changes: false,
+        }
+    }
+
+    /// Only works out the cells next to the ones that changed in the previous
+    /// generation, which is much faster when few cells change. The rule must
+    /// only depend on the cell and its neighbors.
+    pub fn with_worklist(mut self) -> Self {
+        self.track_changes = true;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+}
+
+impl<T, R> Automaton for GridAutomaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&T, &Neighborhood<T>) -> T,
+{
+    fn step(&mut self) -> usize {
+        let (width, height) = (self.grid.width, self.grid.height);
+        let grid = &self.grid;
+        let mut changed = vec![];
+        let mut update = |i: usize| {
+            let p = vec2((i % width) as i32, (i / width) as i32);
+            let around = Neighborhood::new(|d| grid.get(p.x + d.x, p.y + d.y));
+            let t = (self.rule)(&grid.data[i], &around);
+            if t != grid.data[i] {
+                self.next.data[i] = t;
+                changed.push(i);
+            }
+        };
+        match self.worklist.take() {
+            Some(worklist) => worklist.into_iter().for_each(&mut update),
+            None => (0..grid.data.len()).for_each(&mut update),
+        }
+
+        // bring the old buffer up to date, so the buffers match again
+        std::mem::swap(&mut self.grid, &mut self.next);
+        for &i in &changed {
+            self.next.data[i] = self.grid.data[i].clone();
+        }
+
+        if self.track_changes {
+            let mut queued = vec![false; self.grid.data.len()];
+            let mut worklist = vec![];
+            for &i in &changed {
+                let (x, y) = ((i % width) as i32, (i / width) as i32);
+                for (dx, dy) in (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy))) {
+                    let (x, y) = (x + dx, y + dy);
+                    if (0..width as i32).contains(&x) && (0..height as i32).contains(&y) {
+                        let j = x as usize + y as usize * width;
+                        if !queued[j] {
+                            queued[j] = true;
+                            worklist.push(j);
+                        }
+                    }
+                }
+            }
+            self.worklist = Some(worklist);
+        }
+
+        self.generation += 1;
+        changed.len()
+    }
+
+    fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+/// An automaton over a [`SparseGrid`], where empty cells can come to life
+/// anywhere next to a cell that isn't empty, so it can grow without bounds.
+pub struct SparseAutomaton<T, R> {
+    grid: SparseGrid<T>,
+    rule: R,
+    generation: usize,
+    /// the cells to work out in the next step, or `None` for all of them
+    worklist: Option<HashSet<Vec2>>,
+    track_changes: bool,
+}
+
+impl<T, R> SparseAutomaton<T, R>
+where
+    T: PartialEq,
+    R: FnMut(Option<&T>, &Neighborhood<T>) -> Option<T>,
+{
+    /// `rule` gives the next value of a cell from its current value and its
+    /// neighbors, with `None` for empty cells. An empty cell with no neighbors
+    /// must stay empty.
+    pub fn new(grid: SparseGrid<T>, rule: R) -> Self {
+        Self {
+            grid,
+            rule,
+            generation: 0,
+            worklist: None,
+            track_changes: false,
+        }
+    }
+
+    /// same as [`GridAutomaton::with_worklist`]
+    pub fn with_worklist(mut self) -> Self {
+        self.track_changes = true;
+        self
+    }
+
+    pub fn grid(&self) -> &SparseGrid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> SparseGrid<T> {
+        self.grid
+    }
+}
+
+fn around(p: Vec2) -> impl Iterator<Item = Vec2> {
+    (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| p + vec2(dx, dy)))
+}
+
+impl<T, R> Automaton for SparseAutomaton<T, R>
+where
+    T: PartialEq,
+    R: FnMut(Option<&T>, &Neighborhood<T>) -> Option<T>,
+{
+    fn step(&mut self) -> usize {
+        let candidates = self.worklist.take().unwrap_or_else(|| {
+            let cells = self.grid.data.keys();
+            cells.flat_map(|&p| around(p)).collect()
+        });
+
+        let grid = &self.grid;
+        let mut changes = vec![];
+        for p in candidates {
+            let t = grid.get(&p);
+            let around = Neighborhood::new(|d| grid.get(&(p + d)));
+            let next = (self.rule)(t, &around);
+            if next.as_ref() != t {
+                changes.push((p, next));
+            }
+        }
+
+        for (p, t) in &mut changes {
+            match t.take() {
+                Some(t) => self.grid.insert(*p, t),
+                None => self.grid.remove(p),
+            };
+        }
+
+        if self.track_changes {
+            let worklist = changes.iter().flat_map(|&(p, _)| around(p)).collect();
+            self.worklist = Some(worklist);
+        }
+
+        self.generation += 1;
+        changes.len()
+    }
+
+    fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(alive: &bool, around: &Neighborhood<bool>) -> bool {
+        let n = around.count(|&a| a);
+        n == 3 || (*alive && n == 2)
+    }
+
+    fn sparse_life(cell: Option<&()>, around: &Neighborhood<()>) -> Option<()> {
+        let n = around.iter().count();
+        (n == 3 || (cell.is_some() && n == 2)).then_some(())
+    }
+
+    fn grid(s: &str) -> Grid<bool> {
+        Grid::from_str(s, |c| c == '#').unwrap()
+    }
+
+    #[test]
+    fn neighborhood() {
+        let g = Grid::from_vec((0..9).collect(), 3, 3).unwrap();
+        let around = Neighborhood::new(|d| g.get(d.x, d.y));
+        assert_eq!(around.get(-1, -1), None);
+        assert_eq!(around.get(1, 1), Some(&4));
+        assert_eq!(around.iter().copied().collect::<Vec<_>>(), [1, 3, 4]);
+        assert_eq!(around.orthogonal().count(), 2);
+    }
+
+    #[test]
+    fn blinker_and_block() {
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....\n");
+        let mut automaton = GridAutomaton::new(blinker.clone(), life);
+        let run = automaton.run(Some(3));
+        assert_eq!(run.changes, [4, 4, 4]);
+        assert!(!run.fixpoint);
+        assert_eq!(automaton.generation(), 3);
+        automaton.step();
+        assert_eq!(automaton.grid(), &blinker);
+
+        // a block is stable from the start
+        let block = grid("....\n.##.\n.##.\n....\n");
+        let run = GridAutomaton::new(block, life).run(None);
+        assert_eq!(run.changes, [0]);
+        assert!(run.fixpoint);
+    }
+
+    #[test]
+    fn worklist_matches_full_steps() {
+        let start = grid("........\n.#......\n..##....\n.##.....\n........\n........\n");
+        let mut full = GridAutomaton::new(start.clone(), life);
+        let mut worklist = GridAutomaton::new(start, life).with_worklist();
+        for _ in 0..12 {
+            assert_eq!(full.step(), worklist.step());
+            assert_eq!(full.grid(), worklist.grid());
+        }
+    }
+
+    #[test]
+    fn sparse_glider() {
+        let glider = SparseGrid::from_str(".#.\n..#\n###\n", |c| (c == '#').then_some(())).unwrap();
+        let mut automaton = SparseAutomaton::new(glider.clone(), sparse_life).with_worklist();
+        let run = automaton.run(Some(40));
+        assert_eq!(run.generations(), 40);
+        assert!(!run.fixpoint);
+
+        // a glider moves one cell down and right every 4 generations
+        let moved: HashSet<Vec2> = glider.iter().map(|(p, _)| p + vec2(10, 10)).collect();
+        let cells: HashSet<Vec2> = automaton.grid().iter().map(|(p, _)| p).collect();
+        assert_eq!(cells, moved);
+
+        // a lone cell dies, and then nothing is left to change
+        let lone = SparseGrid::from_str("#", |c| (c == '#').then_some(())).unwrap();
+        let run = SparseAutomaton::new(lone, sparse_life).run(None);
+        assert_eq!(run.changes, [1, 0]);
+        assert_eq!(run.total(), 1);
+    }
+}
@@ -0,0 +1,215 @@
+//! Cycle detection for simulations that repeat: the states `x0`, `step(x0)`,
+//! `step(step(x0))`, ... of a sequence that eventually repeats form a cycle,
+//! possibly after some steps that are never seen again. Knowing where the
+//! cycle starts and how long it is gives the state after any number of steps
+//! without simulating all of them.
+//!
+//! The sequence has to repeat at some point, or these never return.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// From step `start` on, the states repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// the first step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// the state after `n` steps from `initial`, which only takes [`Cycle::reduce`] steps
+    pub fn nth<S>(&self, initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.reduce(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Floyd's tortoise and hare, which only keeps a few states around at a time.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare moves twice as fast, so they meet somewhere in the cycle
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let ahead = step(&hare);
+        hare = step(&ahead);
+    }
+
+    // the hare is now a multiple of the cycle length ahead, so moving both at
+    // the same speed from there, they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, which keeps as few states around as [`floyd`], but
+/// usually takes fewer steps.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise jumps to the hare at every power of two, until the hare
+    // catches up with it, which gives the length
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare a cycle length ahead, they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = (0..length).fold(initial, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Remembers every state until one repeats, which takes the fewest steps but
+/// the most memory. Also returns the states up to the repeat, so
+/// `states[cycle.reduce(n)]` is the state after `n` steps.
+pub fn hashed<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return (Cycle { start, length }, states);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+/// The state after `n` steps from `initial`, stopping early once the states
+/// repeat, like [`hashed`].
+pub fn nth<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Grid;
+
+    /// 0, 1, 2, 3, then 1, 2, 3 over and over
+    fn small(x: &u32) -> u32 {
+        if *x < 3 { x + 1 } else { x - 2 }
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let expected = Cycle {
+            start: 1,
+            length: 3,
+        };
+        assert_eq!(floyd(0, small), expected);
+        assert_eq!(brent(0, small), expected);
+        let (cycle, states) = hashed(0, small);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, [0, 1, 2, 3]);
+
+        // a longer tail and cycle, checked against each other
+        let f = |x: &u64| (x * x + 7) % 1009;
+        let (cycle, _) = hashed(2, f);
+        assert_eq!(floyd(2, f), cycle);
+        assert_eq!(brent(2, f), cycle);
+
+        // a sequence that starts in its cycle
+        let g = |x: &u32| (x + 1) % 5;
+        assert_eq!(
+            brent(0, g),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(
+            floyd(0, g),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+    }
+
+    #[test]
+    fn fast_forward() {
+        let cycle = floyd(0, small);
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(10), 1);
+        assert_eq!(cycle.nth(0, small, 1_000_000_000), 1);
+        assert_eq!(nth(0, small, 1_000_000_001), 2);
+        assert_eq!(nth(0, small, 2), 2);
+        assert_eq!(nth(0, small, 0), 0);
+
+        let f = |x: &u64| (x * x + 7) % 1009;
+        let slow = (0..5000).fold(2, |x, _| f(&x));
+        assert_eq!(nth(2, f, 5000), slow);
+        assert_eq!(brent(2, f).nth(2, f, 5000), slow);
+    }
+
+    #[test]
+    fn grid_states() {
+        let grid = Grid::from_str("ab\ncd\n", |c| c).unwrap();
+        let (cycle, states) = hashed(grid.clone(), Grid::rotate_90);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(states[cycle.reduce(1_000_000_001)], grid.rotate_90());
+        assert_eq!(nth(grid.clone(), Grid::rotate_90, 6), grid.rotate_180());
+    }
+}
@@ -40,3 +40,9 @@ impl Dir {
         }
     }
 }
+
+impl From<Dir> for super::Vec2 {
+    fn from(dir: Dir) -> Self {
+        dir.to_vec2()
+    }
+}
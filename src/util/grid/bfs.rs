@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+
+use super::{Grid, NeighborsIterator, OrthogonalNeighborsIterator};
+
+/// Which cells count as adjacent: the 4 orthogonal ones, or all 8 including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+/// either kind of neighbors, without boxing the iterator
+enum Adjacent<'a, T> {
+    Four(OrthogonalNeighborsIterator<'a, T>),
+    Eight(NeighborsIterator<'a, T>),
+}
+
+impl<'a, T> Iterator for Adjacent<'a, T> {
+    type Item = ((i32, i32), &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Adjacent::Four(neighbors) => neighbors.next(),
+            Adjacent::Eight(neighbors) => neighbors.next(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// the cells adjacent to `(x, y)`, see [`Grid::orthogonal_neighbors`] and [`Grid::neighbors`]
+    pub fn adjacent(
+        &self,
+        x: i32,
+        y: i32,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = ((i32, i32), &T)> {
+        match connectivity {
+            Connectivity::Four => {
+                Adjacent::Four(OrthogonalNeighborsIterator::new(self, x, y, false))
+            }
+            Connectivity::Eight => Adjacent::Eight(NeighborsIterator::new(self, x, y, false)),
+        }
+    }
+
+    /// Breadth-first search from `start`, see [`Grid::distances_from`].
+    pub fn distances<F>(
+        &self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: F,
+    ) -> Grid<Option<u32>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.distances_from([start], connectivity, passable)
+    }
+
+    /// Breadth-first search from all of `sources` at once, only stepping onto
+    /// cells that are `passable`. Returns the number of steps to each cell from
+    /// the closest source, or `None` for cells that can't be reached. The
+    /// sources themselves are at distance 0 even if they aren't passable, and
+    /// sources outside the grid are ignored.
+    pub fn distances_from<F>(
+        &self,
+        sources: impl IntoIterator<Item = (i32, i32)>,
+        connectivity: Connectivity,
+        mut passable: F,
+    ) -> Grid<Option<u32>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut distances = Grid::from_scalar(None, self.width, self.height);
+        let mut queue = VecDeque::new();
+        for (x, y) in sources {
+            if let Some(d @ None) = distances.get_mut(x, y) {
+                *d = Some(0);
+                queue.push_back((x, y));
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            let next = distances[(x, y)].map(|d| d + 1);
+            for (p, t) in self.adjacent(x, y, connectivity) {
+                if distances[p].is_none() && passable(t) {
+                    distances[p] = next;
+                    queue.push_back(p);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// the cells that can be reached from `start` by only stepping onto `passable` cells
+    pub fn reachable<F>(
+        &self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: F,
+    ) -> Grid<bool>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let distances = self.distances(start, connectivity, passable);
+        Grid {
+            data: distances.data.iter().map(Option::is_some).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Sets every cell reachable from `start` (including `start`) to `value`,
+    /// only stepping onto `passable` cells. Returns the number of cells filled.
+    pub fn flood_fill<F>(
+        &mut self,
+        start: (i32, i32),
+        connectivity: Connectivity,
+        passable: F,
+        value: T,
+    ) -> usize
+    where
+        F: FnMut(&T) -> bool,
+        T: Clone,
+    {
+        let reachable = self.reachable(start, connectivity, passable);
+        let mut filled = 0;
+        for (t, _) in self.data.iter_mut().zip(reachable.data).filter(|(_, r)| *r) {
+            *t = value.clone();
+            filled += 1;
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+..#.
+.##.
+....
+#.#.
+";
+
+    #[test]
+    fn distance_map() {
+        let grid = Grid::from_str(MAZE, |c| c).unwrap();
+        let d = grid.distances((0, 0), Connectivity::Four, |&c| c == '.');
+        assert_eq!(d[(3, 0)], Some(7));
+        assert_eq!(d[(2, 0)], None);
+        assert_eq!(d[(0, 3)], None);
+        assert_eq!(d[(1, 3)], Some(4));
+
+        let d = grid.distances_from([(0, 0), (3, 0)], Connectivity::Four, |&c| c == '.');
+        assert_eq!(d[(3, 2)], Some(2));
+
+        let d = grid.distances((0, 0), Connectivity::Eight, |&c| c == '.');
+        assert_eq!(d[(3, 0)], Some(5));
+        assert_eq!(d[(1, 3)], Some(3));
+    }
+
+    #[test]
+    fn fill() {
+        let mut grid = Grid::from_str(MAZE, |c| c).unwrap();
+        assert_eq!(
+            grid.flood_fill((3, 3), Connectivity::Four, |&c| c == '.', 'o'),
+            11
+        );
+        assert_eq!(
+            grid.pretty_string(|c| if *c == 'o' { "o" } else { "x" }),
+            "ooxo\noxxo\noooo\nxoxo\n"
+        );
+    }
+}
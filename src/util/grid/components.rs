@@ -0,0 +1,137 @@
+use super::{Connectivity, Grid};
+use crate::util::{Dir, Vec2, vec2};
+
+/// A connected region of cells, see [`Grid::regions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// the number of cells
+    pub area: usize,
+    /// the number of cell edges between the region and anything else,
+    /// including the edge of the grid
+    pub perimeter: usize,
+    /// the number of straight lines the perimeter is made of
+    pub sides: usize,
+    /// the bounding box, inclusive
+    pub top_left: Vec2,
+    pub bottom_right: Vec2,
+}
+
+/// The result of [`Grid::regions`]: a label for every cell, which is its
+/// region's index in `regions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl<T> Grid<T> {
+    /// splits the grid into connected regions of equal cells
+    pub fn regions(&self, connectivity: Connectivity) -> Regions
+    where
+        T: PartialEq,
+    {
+        self.regions_by(connectivity, |a, b| a == b)
+    }
+
+    /// Splits the grid into connected regions, where two adjacent cells are in
+    /// the same region if `same` returns true for them. Regions are labelled in
+    /// the order their first cell appears in, going row by row.
+    pub fn regions_by<F>(&self, connectivity: Connectivity, mut same: F) -> Regions
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut labels: Grid<Option<usize>> = Grid::from_scalar(None, self.width, self.height);
+        let mut count = 0;
+        let mut stack = vec![];
+        for ((x, y), _) in self.iter() {
+            if labels[(x, y)].is_some() {
+                continue;
+            }
+
+            labels[(x, y)] = Some(count);
+            stack.push((x, y));
+            while let Some(p) = stack.pop() {
+                for (q, t) in self.adjacent(p.0, p.1, connectivity) {
+                    if labels[q].is_none() && same(&self[p], t) {
+                        labels[q] = Some(count);
+                        stack.push(q);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        let labels = Grid {
+            data: labels.data.into_iter().map(Option::unwrap).collect(),
+            width: self.width,
+            height: self.height,
+        };
+
+        let mut regions = vec![
+            Region {
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                top_left: vec2(i32::MAX, i32::MAX),
+                bottom_right: vec2(i32::MIN, i32::MIN),
+            };
+            count
+        ];
+        for ((x, y), &label) in labels.iter() {
+            let p = vec2(x, y);
+            let in_region = |p: Vec2| labels.get(p.x, p.y) == Some(&label);
+            let region = &mut regions[label];
+
+            region.area += 1;
+            region.top_left = vec2(region.top_left.x.min(x), region.top_left.y.min(y));
+            region.bottom_right = vec2(region.bottom_right.x.max(x), region.bottom_right.y.max(y));
+
+            for dir in Dir::ALL {
+                let d = dir.to_vec2();
+                if in_region(p + d) {
+                    continue;
+                }
+                region.perimeter += 1;
+
+                // only count a side at one end: if the cell beside this one has
+                // the same edge, the side continues there instead
+                let beside = p + dir.turn_right().to_vec2();
+                if !in_region(beside) || in_region(beside + d) {
+                    region.sides += 1;
+                }
+            }
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn garden() {
+        let grid = Grid::from_str("AAAA\nBBCD\nBBCC\nEEEC\n", |c| c).unwrap();
+        let Regions { labels, regions } = grid.regions(Connectivity::Four);
+
+        let stats: Vec<_> = regions
+            .iter()
+            .map(|r| (r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            stats,
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(labels[(3, 3)], 2);
+        assert_eq!(regions[2].top_left, vec2(2, 1));
+        assert_eq!(regions[2].bottom_right, vec2(3, 3));
+    }
+
+    #[test]
+    fn diagonal() {
+        let grid = Grid::from_str("#.\n.#\n", |c| c).unwrap();
+        assert_eq!(grid.regions(Connectivity::Four).regions.len(), 4);
+        assert_eq!(grid.regions(Connectivity::Eight).regions.len(), 2);
+    }
+}
@@ -0,0 +1,187 @@
+use super::Grid;
+use crate::util::Vec2;
+
+impl<T> Grid<T> {
+    /// the cells of row `y`, from left to right
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// the number of rows `chunks` can't make, because they have no cells
+    fn empty_rows(&self) -> usize {
+        if self.width == 0 { self.height } else { 0 }
+    }
+
+    /// the rows from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        let empty = std::iter::repeat_n(&[][..], self.empty_rows());
+        self.data.chunks(self.width.max(1)).chain(empty)
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let empty = std::iter::repeat_with(<&mut [T]>::default).take(self.empty_rows());
+        self.data.chunks_mut(self.width.max(1)).chain(empty)
+    }
+
+    /// the cells of column `x`, from top to bottom
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        // a grid without rows has no cells to start the column at
+        let cells = self.data.get(x..).unwrap_or_default();
+        cells.iter().step_by(self.width)
+    }
+
+    pub fn col_mut(&mut self, x: usize) -> impl Iterator<Item = &mut T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        let cells = self.data.get_mut(x..).unwrap_or_default();
+        cells.iter_mut().step_by(self.width)
+    }
+
+    /// the columns from left to right
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// the columns from left to right, collected so they can all be borrowed at once
+    pub fn cols_mut(&mut self) -> impl Iterator<Item = Vec<&mut T>> {
+        let mut cols: Vec<Vec<&mut T>> = (0..self.width)
+            .map(|_| Vec::with_capacity(self.height))
+            .collect();
+        for (i, t) in self.data.iter_mut().enumerate() {
+            cols[i % self.width].push(t);
+        }
+        cols.into_iter()
+    }
+
+    /// the number of cells from `from` (inclusive) to the edge of the grid
+    fn ray_len(&self, from: Vec2, step: Vec2) -> usize {
+        assert!(step != Vec2::ZERO, "a ray needs a non-zero step");
+        let mut n = 0;
+        let mut p = from;
+        while self.get(p.x, p.y).is_some() {
+            n += 1;
+            p += step;
+        }
+        n
+    }
+
+    /// Walks from `from` by `step` (a [`Dir`](crate::util::Dir) or any
+    /// non-zero [`Vec2`]) until leaving the grid. Includes `from` itself, unless
+    /// it's outside the grid, in which case the ray is empty.
+    pub fn ray(
+        &self,
+        from: impl Into<Vec2>,
+        step: impl Into<Vec2>,
+    ) -> impl Iterator<Item = (Vec2, &T)> {
+        let (from, step) = (from.into(), step.into());
+        let n = self.ray_len(from, step);
+        (0..n as i32).map(move |k| {
+            let p = from + step * k;
+            (p, &self[p])
+        })
+    }
+
+    /// same as [`Grid::ray`], but the cells can be changed
+    pub fn ray_mut(
+        &mut self,
+        from: impl Into<Vec2>,
+        step: impl Into<Vec2>,
+    ) -> impl Iterator<Item = (Vec2, &mut T)> {
+        let (from, step) = (from.into(), step.into());
+        let n = self.ray_len(from, step);
+        if n == 0 {
+            return Vec::new().into_iter();
+        }
+
+        // the cells of a ray are evenly spaced in `data`
+        let start = from.x as usize + from.y as usize * self.width;
+        let stride = step.x as isize + step.y as isize * self.width as isize;
+        let len = stride.unsigned_abs() * (n - 1) + 1;
+        let cells: Vec<&mut T> = if stride >= 0 {
+            let cells = self.data[start..start + len].iter_mut();
+            cells.step_by(stride.unsigned_abs().max(1)).collect()
+        } else {
+            let cells = self.data[start + 1 - len..=start].iter_mut();
+            cells.step_by(stride.unsigned_abs()).rev().collect()
+        };
+
+        let positions = (0..n as i32).map(|k| from + step * k);
+        positions.zip(cells).collect::<Vec<_>>().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{Dir, vec2};
+
+    fn grid() -> Grid<u32> {
+        Grid::from_vec((0..12).collect(), 4, 3).unwrap()
+    }
+
+    fn values<'a>(ray: impl Iterator<Item = (Vec2, &'a u32)>) -> Vec<u32> {
+        ray.map(|(_, &t)| t).collect()
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let mut g = grid();
+        assert_eq!(g.row(1), [4, 5, 6, 7]);
+        assert_eq!(g.rows().count(), 3);
+        assert_eq!(g.col(2).copied().collect::<Vec<_>>(), [2, 6, 10]);
+        assert_eq!(
+            g.cols().map(|c| c.sum::<u32>()).collect::<Vec<_>>(),
+            [12, 15, 18, 21]
+        );
+
+        g.col_mut(0).for_each(|t| *t = 0);
+        for mut col in g.cols_mut() {
+            *col[2] += 100;
+        }
+        assert_eq!(g.row(2), [100, 109, 110, 111]);
+        assert_eq!(g.row(1), [0, 5, 6, 7]);
+    }
+
+    #[test]
+    fn no_columns() {
+        let mut g = Grid::from_scalar(0u32, 0, 3);
+        assert_eq!(g.rows().count(), 3);
+        assert!(g.rows().all(|row| row.is_empty()));
+        assert_eq!(g.rows_mut().count(), 3);
+        assert_eq!(g.cols().count(), 0);
+        assert_eq!(g.pad(1, 9), Grid::from_scalar(9, 2, 5));
+        assert_eq!(g.tile(2, 2).height, 6);
+    }
+
+    #[test]
+    fn no_rows() {
+        let mut g = Grid::from_scalar(0u32, 3, 0);
+        assert_eq!(g.rows().count(), 0);
+        assert_eq!(g.col(2).count(), 0);
+        assert_eq!(g.col_mut(2).count(), 0);
+        assert_eq!(g.cols().count(), 3);
+        assert!(g.cols_mut().all(|col| col.is_empty()));
+    }
+
+    #[test]
+    fn rays() {
+        let mut g = grid();
+        assert_eq!(values(g.ray((1, 0), Dir::Down)), [1, 5, 9]);
+        assert_eq!(values(g.ray((3, 2), vec2(-1, -1))), [11, 6, 1]);
+        assert_eq!(values(g.ray((3, 0), vec2(-2, 1))), [3, 5]);
+        assert_eq!(g.ray((4, 0), Dir::Left).count(), 0);
+
+        for (_, t) in g.ray_mut((3, 2), Dir::Left) {
+            *t = 0;
+        }
+        for (p, t) in g.ray_mut((0, 0), vec2(1, 1)) {
+            *t = p.x as u32 * 10;
+        }
+        assert_eq!(g.row(2), [0, 0, 20, 0]);
+        assert_eq!(g.row(1), [4, 10, 6, 7]);
+    }
+}
@@ -1,3 +1,34 @@
+use crate::error::{Error, ErrorKind};
+
+mod bfs;
+mod components;
+mod lines;
+mod pattern;
+mod transform;
+mod view;
+mod wrapping;
+
+pub use bfs::Connectivity;
+pub use components::{Region, Regions};
+pub use pattern::Pattern;
+pub use transform::Symmetry;
+pub use view::{GridView, GridViewMut};
+pub use wrapping::{Wrapping, WrappingMut};
+
+/// the offsets of a cell's neighbors, and of the cell itself, row by row
+const NEIGHBORS: [(i32, i32); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+const ORTHOGONAL_NEIGHBORS: [(i32, i32); 5] = [(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)];
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     pub data: Vec<T>,
@@ -72,41 +103,55 @@ impl<T> Grid<T> {
         }
     }
 
-    /// parses a string to a grid, determining width and height automatically based on newlines (`\n`)
-    pub fn from_str<F>(s: &str, mut f: F) -> Option<Self>
+    /// Parses a string to a grid, one row per line. Lines can end in `\n` or
+    /// `\r\n`, and blank lines at the end are ignored. Every row must be as long
+    /// as the first one, otherwise the error says which line is off.
+    pub fn from_str<F>(s: &str, mut f: F) -> Result<Self, Error>
     where
         F: FnMut(char) -> T,
     {
-        let mut data = vec![];
-        let mut width = None;
-        let mut n = 0;
-        for c in s.chars() {
-            if c == '\n' {
-                if let Some(w) = width {
-                    if n == w {
-                        n = 0;
-                        continue;
-                    } else {
-                        return None;
-                    }
-                } else {
-                    width = Some(n);
-                }
+        Self::try_from_str(s, |c| Ok(f(c)))
+    }
 
-                n = 0;
-                continue;
+    /// Same as [`Grid::from_str`], but the mapping can fail, e.g. on a character
+    /// that isn't part of the puzzle. Its errors are tagged with the line and
+    /// column of the character.
+    pub fn try_from_str<F>(s: &str, mut f: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, Error>,
+    {
+        let lines: Vec<&str> = s.trim_end_matches(['\n', '\r']).lines().collect();
+        let Some(first) = lines.first() else {
+            return Err(Error::other("grid is empty"));
+        };
+        let width = first.chars().count();
+
+        let mut data = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut n = 0;
+            for (x, c) in line.chars().enumerate() {
+                let t = f(c).map_err(|e| {
+                    e.with_line(y + 1)
+                        .with_column(x + 1)
+                        .with_text(c.to_string())
+                })?;
+                data.push(t);
+                n += 1;
             }
 
-            data.push(f(c));
-            n += 1;
+            if n != width {
+                return Err(Error::new(ErrorKind::RaggedRow {
+                    expected: width,
+                    found: n,
+                })
+                .with_line(y + 1));
+            }
         }
 
-        let height = data.len() / width?;
-
-        Some(Self {
+        Ok(Self {
             data,
-            width: width?,
-            height,
+            width,
+            height: lines.len(),
         })
     }
 
@@ -150,19 +195,29 @@ impl<T> Grid<T> {
         OrthogonalNeighborsIterator::new(self, x, y, include_self)
     }
 
-    pub fn pretty_print<F>(&self, mut f: F)
+    pub fn pretty_print<F>(&self, f: F)
+    where
+        F: FnMut(&T) -> &str,
+    {
+        print!("{}", self.pretty_string(f))
+    }
+
+    /// same as [`Grid::pretty_print`], but returns the rendered grid instead of printing it
+    pub fn pretty_string<F>(&self, mut f: F) -> String
     where
         F: FnMut(&T) -> &str,
     {
+        let mut s = String::new();
         for y in 0..self.height {
             for x in 0..self.width {
                 let Some(t) = self.get(x as i32, y as i32) else {
                     unreachable!()
                 };
-                print!("{}", f(t))
+                s.push_str(f(t));
             }
-            println!()
+            s.push('\n');
         }
+        s
     }
 }
 
@@ -252,17 +307,7 @@ impl<'a, T> NeighborsIterator<'a, T> {
 impl<'a, T> Iterator for NeighborsIterator<'a, T> {
     type Item = ((i32, i32), &'a T);
     fn next(&mut self) -> Option<Self::Item> {
-        const DIR: &[(i32, i32)] = &[
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-            (-1, 0),
-            (0, 0),
-            (1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-        ];
+        const DIR: &[(i32, i32)] = &NEIGHBORS;
         let mut found = None;
         while found.is_none() {
             if self.i >= DIR.len() {
@@ -307,7 +352,7 @@ impl<'a, T> OrthogonalNeighborsIterator<'a, T> {
 impl<'a, T> Iterator for OrthogonalNeighborsIterator<'a, T> {
     type Item = ((i32, i32), &'a T);
     fn next(&mut self) -> Option<Self::Item> {
-        const DIR: &[(i32, i32)] = &[(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)];
+        const DIR: &[(i32, i32)] = &ORTHOGONAL_NEIGHBORS;
         let mut found = None;
         while found.is_none() {
             if self.i >= DIR.len() {
@@ -328,3 +373,38 @@ impl<'a, T> Iterator for OrthogonalNeighborsIterator<'a, T> {
         found
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        let expected = Grid::from_vec(vec!['a', 'b', 'c', 'd'], 2, 2).unwrap();
+        for s in ["ab\ncd\n", "ab\ncd", "ab\r\ncd\r\n", "ab\ncd\n\n"] {
+            assert_eq!(Grid::from_str(s, |c| c).unwrap(), expected, "{s:?}");
+        }
+        assert!(Grid::from_str("", |c| c).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let e = Grid::from_str("abc\nabc\nab\n", |c| c).unwrap_err();
+        assert!(matches!(
+            e.kind,
+            ErrorKind::RaggedRow {
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(e.line, Some(3));
+
+        let e = Grid::try_from_str("..\n.x", |c| match c {
+            '.' => Ok(()),
+            _ => Err(Error::new(ErrorKind::Unexpected)),
+        })
+        .unwrap_err();
+        assert_eq!((e.line, e.column), (Some(2), Some(2)));
+        assert_eq!(e.text.as_deref(), Some("x"));
+    }
+}
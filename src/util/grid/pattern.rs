@@ -0,0 +1,198 @@
+use super::{Grid, Symmetry};
+use crate::util::{Vec2, vec2};
+
+/// A small grid to look for inside bigger ones, see [`Grid::find_pattern`].
+/// Only some of its cells matter, the rest match anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern<P> {
+    width: usize,
+    height: usize,
+    /// the cells that matter, row by row
+    cells: Vec<(Vec2, P)>,
+}
+
+impl<P> Pattern<P> {
+    /// a pattern the size of `grid`, where only the cells `cares` returns true for matter
+    pub fn new(grid: &Grid<P>, mut cares: impl FnMut(&P) -> bool) -> Self
+    where
+        P: Clone,
+    {
+        Self {
+            width: grid.width,
+            height: grid.height,
+            cells: grid
+                .iter()
+                .filter(|(_, p)| cares(p))
+                .map(|(pos, p)| (pos.into(), p.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// the cells that matter, relative to the top left corner
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, &P)> {
+        self.cells.iter().map(|(pos, p)| (*pos, p))
+    }
+
+    /// the pattern rotated or reflected like [`Grid::transform`]
+    pub fn transform(&self, symmetry: Symmetry) -> Self
+    where
+        P: Clone,
+    {
+        let (width, height) = if symmetry.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+
+        // the cell at `pos` ends up where the inverse transformation takes it from
+        let inverse = symmetry.inverse();
+        let mut cells: Vec<_> = self
+            .cells
+            .iter()
+            .map(|(pos, p)| {
+                let (x, y) = inverse.source(pos.x as usize, pos.y as usize, width, height);
+                (vec2(x as i32, y as i32), p.clone())
+            })
+            .collect();
+        cells.sort_by_key(|(pos, _)| (pos.y, pos.x));
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// the rotations and reflections of the pattern that differ from each other,
+    /// in the order of [`Symmetry::ALL`]
+    pub fn symmetries(&self) -> Vec<(Symmetry, Self)>
+    where
+        P: Clone + PartialEq,
+    {
+        let mut distinct: Vec<(Symmetry, Self)> = vec![];
+        for s in Symmetry::ALL {
+            let pattern = self.transform(s);
+            if distinct.iter().all(|(_, p)| *p != pattern) {
+                distinct.push((s, pattern));
+            }
+        }
+        distinct
+    }
+}
+
+impl<T> Grid<T> {
+    /// Whether `pattern` matches with its top left corner at `at`: it has to
+    /// fit in the grid, and `matches` has to be true for every cell of the
+    /// pattern that matters, along with the cell of the grid under it.
+    pub fn matches_at<P>(
+        &self,
+        pattern: &Pattern<P>,
+        at: impl Into<Vec2>,
+        mut matches: impl FnMut(&T, &P) -> bool,
+    ) -> bool {
+        let at = at.into();
+        let fits = at.x >= 0
+            && at.y >= 0
+            && at.x as usize + pattern.width <= self.width
+            && at.y as usize + pattern.height <= self.height;
+        fits && pattern.cells().all(|(d, p)| matches(&self[at + d], p))
+    }
+
+    /// every position `pattern` matches at, as in [`Grid::matches_at`], row by row
+    pub fn find_pattern<P>(
+        &self,
+        pattern: &Pattern<P>,
+        mut matches: impl FnMut(&T, &P) -> bool,
+    ) -> Vec<Vec2> {
+        if pattern.width > self.width || pattern.height > self.height {
+            return vec![];
+        }
+
+        // where each cell that matters is in `data`, relative to the top left corner
+        let offsets: Vec<(usize, &P)> = pattern
+            .cells()
+            .map(|(d, p)| (d.x as usize + d.y as usize * self.width, p))
+            .collect();
+
+        let mut found = vec![];
+        for y in 0..=self.height - pattern.height {
+            for x in 0..=self.width - pattern.width {
+                let corner = x + y * self.width;
+                if offsets
+                    .iter()
+                    .all(|&(offset, p)| matches(&self.data[corner + offset], p))
+                {
+                    found.push(vec2(x as i32, y as i32));
+                }
+            }
+        }
+        found
+    }
+
+    /// Same as [`Grid::find_pattern`], but for every distinct rotation and
+    /// reflection of `pattern`, along with the one that matched.
+    pub fn find_pattern_symmetries<P>(
+        &self,
+        pattern: &Pattern<P>,
+        mut matches: impl FnMut(&T, &P) -> bool,
+    ) -> Vec<(Symmetry, Vec2)>
+    where
+        P: Clone + PartialEq,
+    {
+        let mut found = vec![];
+        for (s, pattern) in pattern.symmetries() {
+            let positions = self.find_pattern(&pattern, &mut matches);
+            found.extend(positions.into_iter().map(|pos| (s, pos)));
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::from_str(s, |c| c).unwrap()
+    }
+
+    #[test]
+    fn find() {
+        let g = grid("#.#.\n.#..\n#.#.\n...#\n");
+        let pattern = Pattern::new(&grid("#.\n?#\n"), |&c| c != '?');
+        assert_eq!(pattern.cells().count(), 3);
+
+        let found = g.find_pattern(&pattern, |a, b| a == b);
+        assert_eq!(found, [vec2(0, 0), vec2(1, 1), vec2(2, 2)]);
+        assert!(g.matches_at(&pattern, (2, 2), |a, b| a == b));
+        assert!(!g.matches_at(&pattern, (3, 3), |a, b| a == b));
+        assert!(!g.matches_at(&pattern, (-1, 0), |_, _| true));
+
+        // only the cells under '#' have to match
+        let found = g.find_pattern(&pattern, |a, b| *b == '.' || a == b);
+        assert_eq!(found.len(), 3);
+        assert_eq!(g.find_pattern(&pattern, |_, _| true).len(), 9);
+    }
+
+    #[test]
+    fn find_symmetries() {
+        let g = grid(".....\n.##..\n..#..\n.....\n");
+        let pattern = Pattern::new(&grid("##\n#?\n"), |&c| c == '#');
+        assert_eq!(pattern.symmetries().len(), 4);
+        assert_eq!(
+            pattern.transform(Symmetry::Rotate90),
+            Pattern::new(&grid("##\n?#\n"), |&c| c == '#')
+        );
+
+        let found = g.find_pattern_symmetries(&pattern, |a, b| a == b);
+        assert_eq!(found, [(Symmetry::Rotate90, vec2(1, 1))]);
+    }
+}
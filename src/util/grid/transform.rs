@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use super::Grid;
+
+/// One of the 8 ways to rotate and reflect a rectangle onto itself.
+/// Rotations are clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// mirrors left and right
+    FlipHorizontal,
+    /// mirrors top and bottom
+    FlipVertical,
+    /// mirrors along the main diagonal, from top-left to bottom-right
+    Transpose,
+    /// mirrors along the other diagonal, from top-right to bottom-left
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// whether the transformed grid's width and height are swapped
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Symmetry::Rotate90
+                | Symmetry::Rotate270
+                | Symmetry::Transpose
+                | Symmetry::AntiTranspose
+        )
+    }
+
+    /// the transformation that undoes this one
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            s => s,
+        }
+    }
+
+    /// the position in a `width` by `height` grid that ends up at `(x, y)` after
+    /// the transformation
+    pub fn source(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let (w, h) = (width, height);
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (y, h - 1 - x),
+            Symmetry::Rotate180 => (w - 1 - x, h - 1 - y),
+            Symmetry::Rotate270 => (w - 1 - y, x),
+            Symmetry::FlipHorizontal => (w - 1 - x, y),
+            Symmetry::FlipVertical => (x, h - 1 - y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (w - 1 - y, h - 1 - x),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transform(&self, symmetry: Symmetry) -> Grid<T> {
+        let (width, height) = if symmetry.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (sx, sy) = symmetry.source(x, y, self.width, self.height);
+                data.push(self.data[sx + sy * self.width].clone());
+            }
+        }
+
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// rotates the grid clockwise by 90 degrees
+    pub fn rotate_90(&self) -> Grid<T> {
+        self.transform(Symmetry::Rotate90)
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        self.transform(Symmetry::Rotate180)
+    }
+
+    /// rotates the grid clockwise by 270 degrees, or counterclockwise by 90
+    pub fn rotate_270(&self) -> Grid<T> {
+        self.transform(Symmetry::Rotate270)
+    }
+
+    /// mirrors the grid left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.transform(Symmetry::FlipHorizontal)
+    }
+
+    /// mirrors the grid top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.transform(Symmetry::FlipVertical)
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.transform(Symmetry::Transpose)
+    }
+
+    /// all 8 rotations and reflections of the grid, in the order of [`Symmetry::ALL`]
+    pub fn symmetries(&self) -> impl Iterator<Item = (Symmetry, Grid<T>)> + '_ {
+        Symmetry::ALL.into_iter().map(|s| (s, self.transform(s)))
+    }
+
+    /// the rotations and reflections of the grid that differ from each other,
+    /// e.g. only 1 for a square filled with a single value
+    pub fn distinct_symmetries(&self) -> impl Iterator<Item = (Symmetry, Grid<T>)> + '_
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        self.symmetries()
+            .filter(move |(_, g)| seen.insert(g.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::from_str(s, |c| c).unwrap()
+    }
+
+    #[test]
+    fn rectangular() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.rotate_90(), grid("da\neb\nfc\n"));
+        assert_eq!(g.rotate_180(), grid("fed\ncba\n"));
+        assert_eq!(g.rotate_270(), grid("cf\nbe\nad\n"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed\n"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc\n"));
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf\n"));
+        assert_eq!(g.transform(Symmetry::AntiTranspose), grid("fc\neb\nda\n"));
+        assert_eq!(g.rotate_90().rotate_90().rotate_90().rotate_90(), g);
+        assert_eq!(g.rotate_90().flip_horizontal(), g.transpose());
+        for s in Symmetry::ALL {
+            assert_eq!(g.transform(s).transform(s.inverse()), g);
+        }
+    }
+
+    #[test]
+    fn distinct() {
+        assert_eq!(grid("##\n##\n").distinct_symmetries().count(), 1);
+        assert_eq!(grid("##\n").distinct_symmetries().count(), 2);
+        assert_eq!(grid("#.\n##\n").distinct_symmetries().count(), 4);
+        assert_eq!(grid("#..\n###\n").distinct_symmetries().count(), 8);
+    }
+}
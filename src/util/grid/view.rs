@@ -0,0 +1,386 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, NEIGHBORS, ORTHOGONAL_NEIGHBORS};
+use crate::util::Vec2;
+
+/// A rectangle of a grid, with positions relative to its top left corner. See
+/// [`Grid::view`].
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Vec2,
+    width: usize,
+    height: usize,
+}
+
+/// same as [`GridView`], but the cells can be changed, see [`Grid::view_mut`]
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    origin: Vec2,
+    width: usize,
+    height: usize,
+}
+
+// not derived, since that would require `T: Copy`
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Grid<T> {
+    fn check_rect(&self, top_left: Vec2, width: usize, height: usize) {
+        assert!(
+            top_left.x >= 0
+                && top_left.y >= 0
+                && top_left.x as usize + width <= self.width
+                && top_left.y as usize + height <= self.height,
+            "a {width}x{height} view at ({}, {}) doesn't fit in a {}x{} grid",
+            top_left.x,
+            top_left.y,
+            self.width,
+            self.height
+        );
+    }
+
+    /// the `width` by `height` rectangle with its top left corner at `top_left`
+    pub fn view(&self, top_left: impl Into<Vec2>, width: usize, height: usize) -> GridView<'_, T> {
+        let origin = top_left.into();
+        self.check_rect(origin, width, height);
+        GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// same as [`Grid::view`], but the cells can be changed
+    pub fn view_mut(
+        &mut self,
+        top_left: impl Into<Vec2>,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'_, T> {
+        let origin = top_left.into();
+        self.check_rect(origin, width, height);
+        GridViewMut {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// a copy of the `width` by `height` rectangle with its top left corner at `top_left`
+    pub fn crop(&self, top_left: impl Into<Vec2>, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.view(top_left, width, height).to_grid()
+    }
+
+    /// a copy of the grid with a border `n` cells wide of `value` all around it
+    pub fn pad(&self, n: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut padded = Grid::from_scalar(value, self.width + 2 * n, self.height + 2 * n);
+        for (row, padded_row) in self.rows().zip(padded.rows_mut().skip(n)) {
+            padded_row[n..n + self.width].clone_from_slice(row);
+        }
+        padded
+    }
+
+    /// the grid repeated `nx` times across and `ny` times down
+    pub fn tile(&self, nx: usize, ny: usize) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width * nx;
+        let mut data = Vec::with_capacity(width * self.height * ny);
+        for _ in 0..ny {
+            for row in self.rows() {
+                for _ in 0..nx {
+                    data.extend_from_slice(row);
+                }
+            }
+        }
+        Self {
+            data,
+            width,
+            height: self.height * ny,
+        }
+    }
+
+    /// A copy of the grid with a new size, keeping the top left corner. Cells
+    /// that don't fit are dropped, and new cells are `value`.
+    pub fn resize(&self, width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut resized = Grid::from_scalar(value, width, height);
+        let w = width.min(self.width);
+        for (row, resized_row) in self.rows().zip(resized.rows_mut()) {
+            resized_row[..w].clone_from_slice(&row[..w]);
+        }
+        resized
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// the position of the view's top left corner in the grid
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    /// the cell at `(x, y)` relative to the view, if it's in the view
+    pub fn get(&self, x: i32, y: i32) -> Option<&'a T> {
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.grid.get(self.origin.x + x, self.origin.y + y)
+        } else {
+            None
+        }
+    }
+
+    /// the cells of the view, row by row, with positions relative to the view
+    pub fn iter(self) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        let (x, w) = (self.origin.x as usize, self.width);
+        let rows = self
+            .grid
+            .rows()
+            .skip(self.origin.y as usize)
+            .take(self.height);
+        rows.enumerate().flat_map(move |(y, row)| {
+            let cells = row[x..x + w].iter().enumerate();
+            cells.map(move |(x, t)| ((x as i32, y as i32), t))
+        })
+    }
+
+    /// same as [`Grid::neighbors`], leaving out cells outside the view
+    pub fn neighbors(
+        self,
+        x: i32,
+        y: i32,
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        self.offsets(x, y, &NEIGHBORS, include_self)
+    }
+
+    /// same as [`Grid::orthogonal_neighbors`], leaving out cells outside the view
+    pub fn orthogonal_neighbors(
+        self,
+        x: i32,
+        y: i32,
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        self.offsets(x, y, &ORTHOGONAL_NEIGHBORS, include_self)
+    }
+
+    fn offsets(
+        self,
+        x: i32,
+        y: i32,
+        offsets: &'static [(i32, i32)],
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        offsets
+            .iter()
+            .filter(move |&&d| include_self || d != (0, 0))
+            .filter_map(move |&(dx, dy)| {
+                let p = (x + dx, y + dy);
+                Some((p, self.get(p.0, p.1)?))
+            })
+    }
+
+    /// a copy of the cells in the view
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: self.iter().map(|(_, t)| t.clone()).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> GridViewMut<'_, T> {
+    /// a read-only view of the same rectangle
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            origin: self.origin,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.as_view().get(x, y)
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.grid.get_mut(self.origin.x + x, self.origin.y + y)
+        } else {
+            None
+        }
+    }
+
+    /// same as [`GridView::iter`], but the cells can be changed
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        let (x, w) = (self.origin.x as usize, self.width);
+        let rows = self.grid.rows_mut().skip(self.origin.y as usize);
+        rows.take(self.height)
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                let cells = row[x..x + w].iter_mut().enumerate();
+                cells.map(move |(x, t)| ((x as i32, y as i32), t))
+            })
+    }
+
+    /// sets every cell in the view to `value`
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for (_, t) in self.iter_mut() {
+            *t = value.clone();
+        }
+    }
+}
+
+impl<T> Index<(i32, i32)> for GridView<'_, T> {
+    type Output = T;
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the view size is ({}, {}), but the index is ({x}, {y})",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> Index<Vec2> for GridView<'_, T> {
+    type Output = T;
+    fn index(&self, Vec2 { x, y }: Vec2) -> &Self::Output {
+        &self[(x, y)]
+    }
+}
+
+impl<T> Index<(i32, i32)> for GridViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("index out of bounds: index is ({x}, {y})"))
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("index out of bounds: index is ({x}, {y})"))
+    }
+}
+
+impl<T> Index<Vec2> for GridViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, Vec2 { x, y }: Vec2) -> &Self::Output {
+        &self[(x, y)]
+    }
+}
+
+impl<T> IndexMut<Vec2> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, Vec2 { x, y }: Vec2) -> &mut Self::Output {
+        &mut self[(x, y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::vec2;
+
+    /// a 4x3 grid holding 0 to 11
+    fn grid() -> Grid<u32> {
+        Grid::from_vec((0..12).collect(), 4, 3).unwrap()
+    }
+
+    #[test]
+    fn views() {
+        let g = grid();
+        let v = g.view((1, 1), 2, 2);
+        assert_eq!(v[(0, 0)], 5);
+        assert_eq!(v[vec2(1, 1)], 10);
+        assert_eq!(v.get(2, 0), None);
+        assert_eq!(v.get(-1, 0), None);
+
+        let cells: Vec<_> = v.iter().collect();
+        assert_eq!(
+            cells,
+            [((0, 0), &5), ((1, 0), &6), ((0, 1), &9), ((1, 1), &10)]
+        );
+        let mut around: Vec<_> = v.neighbors(0, 0, false).map(|(_, &t)| t).collect();
+        around.sort();
+        assert_eq!(around, [6, 9, 10]);
+        assert_eq!(v.orthogonal_neighbors(1, 0, true).count(), 3);
+    }
+
+    #[test]
+    fn mutable_views() {
+        let mut g = grid();
+        let mut v = g.view_mut(vec2(2, 0), 2, 3);
+        v[(0, 0)] = 100;
+        *v.get_mut(1, 2).unwrap() += 100;
+        assert_eq!(v.get_mut(2, 0), None);
+        for ((x, _), t) in v.iter_mut() {
+            *t += x as u32;
+        }
+        assert_eq!(v.as_view()[(1, 1)], 8);
+        assert_eq!(g.row(0), [0, 1, 100, 4]);
+        assert_eq!(g.row(2), [8, 9, 10, 112]);
+
+        g.view_mut((0, 1), 3, 1).fill(0);
+        assert_eq!(g.row(1), [0, 0, 0, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_outside_grid() {
+        grid().view((3, 0), 2, 1);
+    }
+
+    #[test]
+    fn owned_copies() {
+        let g = grid();
+        assert_eq!(g.crop((1, 1), 3, 2).data, [5, 6, 7, 9, 10, 11]);
+
+        let padded = g.pad(1, 99);
+        assert_eq!((padded.width, padded.height), (6, 5));
+        assert_eq!(padded.row(0), [99; 6]);
+        assert_eq!(padded.row(2), [99, 4, 5, 6, 7, 99]);
+
+        let small = Grid::from_vec(vec![1, 2], 2, 1).unwrap();
+        let tiled = small.tile(3, 2);
+        assert_eq!((tiled.width, tiled.height), (6, 2));
+        assert_eq!(tiled.row(1), [1, 2, 1, 2, 1, 2]);
+
+        let resized = g.resize(2, 4, 0);
+        assert_eq!(resized.data, [0, 1, 4, 5, 8, 9, 0, 0]);
+        assert_eq!(g.resize(5, 1, 7).data, [0, 1, 2, 3, 7]);
+    }
+}
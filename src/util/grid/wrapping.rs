@@ -0,0 +1,313 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Grid, NEIGHBORS, ORTHOGONAL_NEIGHBORS};
+use crate::util::Vec2;
+
+/// A view of a grid where the edges wrap around, so the grid behaves like a
+/// torus: every position is in bounds, and `(-1, 0)` is the last cell of the
+/// first row. See [`Grid::wrapping`].
+#[derive(Debug)]
+pub struct Wrapping<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+/// same as [`Wrapping`], but the cells can be changed, see [`Grid::wrapping_mut`]
+#[derive(Debug)]
+pub struct WrappingMut<'a, T> {
+    grid: &'a mut Grid<T>,
+}
+
+// not derived, since that would require `T: Copy`
+impl<T> Clone for Wrapping<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Wrapping<'_, T> {}
+
+impl<T> Grid<T> {
+    /// a view of the grid where positions wrap around the edges
+    pub fn wrapping(&self) -> Wrapping<'_, T> {
+        assert!(!self.data.is_empty(), "an empty grid can't wrap around");
+        Wrapping { grid: self }
+    }
+
+    /// same as [`Grid::wrapping`], but the cells can be changed
+    pub fn wrapping_mut(&mut self) -> WrappingMut<'_, T> {
+        assert!(!self.data.is_empty(), "an empty grid can't wrap around");
+        WrappingMut { grid: self }
+    }
+}
+
+impl<'a, T> Wrapping<'a, T> {
+    /// the grid being viewed
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// the position in the grid that `(x, y)` wraps around to
+    pub fn wrap(&self, x: i32, y: i32) -> (i32, i32) {
+        (
+            x.rem_euclid(self.grid.width as i32),
+            y.rem_euclid(self.grid.height as i32),
+        )
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> &'a T {
+        &self.grid[self.wrap(x, y)]
+    }
+
+    /// Same as [`Grid::neighbors`], with the positions wrapped into the grid.
+    /// On grids less than 3 cells wide or tall, the same cell can be a
+    /// neighbor more than once.
+    pub fn neighbors(
+        self,
+        x: i32,
+        y: i32,
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        self.offsets(x, y, &NEIGHBORS, include_self)
+    }
+
+    /// same as [`Grid::orthogonal_neighbors`], with the positions wrapped into the grid
+    pub fn orthogonal_neighbors(
+        self,
+        x: i32,
+        y: i32,
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        self.offsets(x, y, &ORTHOGONAL_NEIGHBORS, include_self)
+    }
+
+    fn offsets(
+        self,
+        x: i32,
+        y: i32,
+        offsets: &'static [(i32, i32)],
+        include_self: bool,
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        // wrap first, so adding the offsets can't overflow
+        let (x, y) = self.wrap(x, y);
+        offsets
+            .iter()
+            .filter(move |&&d| include_self || d != (0, 0))
+            .map(move |&(dx, dy)| {
+                let p = self.wrap(x + dx, y + dy);
+                (p, self.get(p.0, p.1))
+            })
+    }
+
+    /// Same as [`Grid::ray`], but instead of stopping at the edge the ray wraps
+    /// around, until it would come back to the cell it started on.
+    pub fn ray(
+        self,
+        from: impl Into<Vec2>,
+        step: impl Into<Vec2>,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> {
+        let (from, step) = (from.into(), step.into());
+        assert!(step != Vec2::ZERO, "a ray needs a non-zero step");
+        let start = Vec2::from(self.wrap(from.x, from.y));
+        // a step by the whole width or height goes nowhere, and wrapping the
+        // step first means adding it can't overflow
+        let step = Vec2::from(self.wrap(step.x, step.y));
+        let mut next = Some(start);
+        std::iter::from_fn(move || {
+            let p = next?;
+            let q = Vec2::from(self.wrap(p.x + step.x, p.y + step.y));
+            next = (q != start).then_some(q);
+            Some((p, &self.grid[p]))
+        })
+    }
+}
+
+impl<T> WrappingMut<'_, T> {
+    /// a read-only view of the same grid, for neighbors and rays
+    pub fn as_wrapping(&self) -> Wrapping<'_, T> {
+        Wrapping { grid: self.grid }
+    }
+
+    /// the position in the grid that `(x, y)` wraps around to
+    pub fn wrap(&self, x: i32, y: i32) -> (i32, i32) {
+        self.as_wrapping().wrap(x, y)
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> &T {
+        &self.grid[self.wrap(x, y)]
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> &mut T {
+        let p = self.wrap(x, y);
+        &mut self.grid[p]
+    }
+}
+
+impl<T> Index<(i32, i32)> for Wrapping<'_, T> {
+    type Output = T;
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        self.get(x, y)
+    }
+}
+
+impl<T> Index<Vec2> for Wrapping<'_, T> {
+    type Output = T;
+    fn index(&self, Vec2 { x, y }: Vec2) -> &Self::Output {
+        self.get(x, y)
+    }
+}
+
+impl<T> Index<(i32, i32)> for WrappingMut<'_, T> {
+    type Output = T;
+    fn index(&self, (x, y): (i32, i32)) -> &Self::Output {
+        self.get(x, y)
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for WrappingMut<'_, T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut Self::Output {
+        self.get_mut(x, y)
+    }
+}
+
+impl<T> Index<Vec2> for WrappingMut<'_, T> {
+    type Output = T;
+    fn index(&self, Vec2 { x, y }: Vec2) -> &Self::Output {
+        self.get(x, y)
+    }
+}
+
+impl<T> IndexMut<Vec2> for WrappingMut<'_, T> {
+    fn index_mut(&mut self, Vec2 { x, y }: Vec2) -> &mut Self::Output {
+        self.get_mut(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{Dir, vec2};
+
+    /// a 4x3 grid holding 0 to 11
+    fn grid() -> Grid<u32> {
+        Grid::from_vec((0..12).collect(), 4, 3).unwrap()
+    }
+
+    #[test]
+    fn negative_positions() {
+        let g = grid();
+        let w = g.wrapping();
+        assert_eq!(w.wrap(-1, -1), (3, 2));
+        assert_eq!(w[(-1, 0)], 3);
+        assert_eq!(w[(0, -1)], 8);
+        assert_eq!(w[(-4, -3)], 0);
+        assert_eq!(w[(-5, -4)], 11);
+        assert_eq!(w[vec2(-2, 1)], 6);
+    }
+
+    #[test]
+    fn far_out_positions() {
+        let g = grid();
+        let w = g.wrapping();
+        assert_eq!(w[(4, 3)], 0);
+        assert_eq!(w[(401, 302)], 9);
+        assert_eq!(w[(-1_000_001, 1_000_000)], 7);
+        assert_eq!(w.wrap(i32::MAX, i32::MIN), (3, 1));
+        assert_eq!(w.wrap(i32::MIN, i32::MAX), (0, 1));
+    }
+
+    #[test]
+    fn wrapped_neighbors() {
+        let g = grid();
+        let w = g.wrapping();
+        let mut around: Vec<_> = w.neighbors(0, 0, false).map(|(_, &t)| t).collect();
+        around.sort();
+        assert_eq!(around, [1, 3, 4, 5, 7, 8, 9, 11]);
+
+        let orthogonal: Vec<_> = w.orthogonal_neighbors(-4, 5, true).collect();
+        assert_eq!(
+            orthogonal,
+            [
+                ((0, 1), &4),
+                ((3, 2), &11),
+                ((0, 2), &8),
+                ((1, 2), &9),
+                ((0, 0), &0)
+            ]
+        );
+
+        // positions as far out as they go, which wrap to (3, 1) and (0, 1)
+        let mut around: Vec<_> = w
+            .neighbors(i32::MAX, i32::MIN, false)
+            .map(|(_, &t)| t)
+            .collect();
+        around.sort();
+        assert_eq!(around, [0, 2, 3, 4, 6, 8, 10, 11]);
+        let orthogonal: Vec<_> = w.orthogonal_neighbors(i32::MIN, i32::MAX, false).collect();
+        assert_eq!(
+            orthogonal,
+            [((0, 0), &0), ((3, 1), &7), ((1, 1), &5), ((0, 2), &8)]
+        );
+
+        // on a 1x1 grid, every neighbor is the cell itself
+        let tiny = Grid::from_scalar('x', 1, 1);
+        assert_eq!(tiny.wrapping().neighbors(-7, 9, true).count(), 9);
+    }
+
+    #[test]
+    fn cells_of_any_type() {
+        // the view can be used more than once even if the cells can't be copied
+        struct Cell(u32);
+        let g = Grid {
+            data: (0..12).map(Cell).collect(),
+            width: 4,
+            height: 3,
+        };
+        let w = g.wrapping();
+        assert_eq!(w.neighbors(0, 0, false).count(), 8);
+        let sum: u32 = w.orthogonal_neighbors(0, 0, false).map(|(_, c)| c.0).sum();
+        assert_eq!(sum, 16);
+    }
+
+    #[test]
+    fn wrapped_rays() {
+        let g = grid();
+        let w = g.wrapping();
+        let cells = |from: Vec2, step: Vec2| {
+            let ray = w.ray(from, step);
+            ray.map(|(_, &t)| t).collect::<Vec<_>>()
+        };
+        assert_eq!(cells(vec2(2, -1), Dir::Right.into()), [10, 11, 8, 9]);
+        assert_eq!(cells(vec2(-3, 0), Dir::Up.into()), [1, 9, 5]);
+        assert_eq!(
+            cells(vec2(0, 0), vec2(1, 1)),
+            [0, 5, 10, 3, 4, 9, 2, 7, 8, 1, 6, 11]
+        );
+        assert_eq!(cells(vec2(1, 1), vec2(4, -6)), [5]);
+        assert_eq!(cells(vec2(0, 0), vec2(-2, 0)), [0, 2]);
+
+        // positions and steps as far out as they go
+        assert_eq!(
+            cells(vec2(i32::MAX, i32::MIN), Dir::Right.into()),
+            [7, 4, 5, 6]
+        );
+        assert_eq!(cells(vec2(0, 0), vec2(i32::MAX, 0)), [0, 3, 2, 1]);
+        assert_eq!(cells(vec2(i32::MIN, 0), vec2(i32::MIN, 1)), [0, 4, 8]);
+        assert_eq!(
+            cells(vec2(0, 0), vec2(i32::MAX, i32::MAX)),
+            [0, 7, 10, 1, 4, 11, 2, 5, 8, 3, 6, 9]
+        );
+    }
+
+    #[test]
+    fn wrapped_writes() {
+        let mut g = grid();
+        let mut w = g.wrapping_mut();
+        w[(-1, -1)] = 100;
+        *w.get_mut(9, -9) += 1000;
+        w[vec2(4, 4)] = 0;
+        assert_eq!(w.as_wrapping().orthogonal_neighbors(0, 0, false).count(), 4);
+        assert_eq!(g.row(2), [8, 9, 10, 100]);
+        assert_eq!(g.row(0), [0, 1001, 2, 3]);
+        assert_eq!(g.row(1), [0, 5, 6, 7]);
+    }
+}
@@ -1,9 +1,12 @@
+pub mod automaton;
 pub mod binsearch;
+pub mod cycle;
 pub mod dir;
 pub mod ext;
 pub mod grid;
 pub mod math;
 pub mod parse_ints;
+pub mod search;
 pub mod sparse_grid;
 pub mod stats;
 pub mod vec2;
@@ -19,6 +22,18 @@ pub use sparse_grid::SparseGrid;
 pub use vec2::{Vec2, vec2};
 pub use vec3::{Vec3, vec3};
 
-pub fn read_file(filename: &str) -> Result<String, std::io::Error> {
-    std::fs::read_to_string(filename)
+pub fn read_file(filename: &str) -> Result<String, crate::error::Error> {
+    std::fs::read_to_string(filename).map_err(|e| crate::error::Error::from(e).with_text(filename))
+}
+
+/// reads a puzzle input from a file, or from standard input if `path` is `-`
+pub fn read_input(path: &str) -> Result<String, crate::error::Error> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut input)
+            .map_err(|e| crate::error::Error::from(e).with_text("<stdin>"))?;
+        Ok(input)
+    } else {
+        read_file(path)
+    }
 }
@@ -1,18 +1,29 @@
-pub fn parse_ints_u64(s: &str) -> Result<Vec<u64>, std::num::ParseIntError> {
+use crate::error::Error;
+
+/// Locates a failed integer within `s`, so a bad number in a multi-line chunk
+/// is reported with its line and column relative to the start of the chunk.
+fn int_error(e: std::num::ParseIntError, s: &str, start: usize, end: usize) -> Error {
+    let before = &s[..start];
+    let line = before.matches('\n').count() + 1;
+    let column = start - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    Error::from(e)
+        .with_line(line)
+        .with_column(column)
+        .with_text(&s[start..=end])
+}
+
+pub fn parse_ints_u64(s: &str) -> Result<Vec<u64>, Error> {
     let mut buf = Vec::new();
     parse_ints_buf_u64(s, &mut buf)?;
     Ok(buf)
 }
 
-pub fn parse_ints_buf_u64(s: &str, buf: &mut Vec<u64>) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_buf_u64(s: &str, buf: &mut Vec<u64>) -> Result<(), Error> {
     parse_ints_radix_buf_u64(s, 10, buf)
 }
 
-pub fn parse_ints_radix_buf_u64(
-    s: &str,
-    radix: u32,
-    buf: &mut Vec<u64>,
-) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_radix_buf_u64(s: &str, radix: u32, buf: &mut Vec<u64>) -> Result<(), Error> {
     let parsed = buf;
 
     let mut char_indices = s.char_indices();
@@ -36,7 +47,7 @@ pub fn parse_ints_radix_buf_u64(
 
         if let Some((start, end)) = current {
             let n_str = &s[start..=end];
-            let n = u64::from_str_radix(n_str, radix)?;
+            let n = u64::from_str_radix(n_str, radix).map_err(|e| int_error(e, s, start, end))?;
             parsed.push(n);
             current = None;
         }
@@ -49,21 +60,17 @@ pub fn parse_ints_radix_buf_u64(
     Ok(())
 }
 
-pub fn parse_ints_u32(s: &str) -> Result<Vec<u32>, std::num::ParseIntError> {
+pub fn parse_ints_u32(s: &str) -> Result<Vec<u32>, Error> {
     let mut buf = Vec::new();
     parse_ints_buf_u32(s, &mut buf)?;
     Ok(buf)
 }
 
-pub fn parse_ints_buf_u32(s: &str, buf: &mut Vec<u32>) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_buf_u32(s: &str, buf: &mut Vec<u32>) -> Result<(), Error> {
     parse_ints_radix_buf_u32(s, 10, buf)
 }
 
-pub fn parse_ints_radix_buf_u32(
-    s: &str,
-    radix: u32,
-    buf: &mut Vec<u32>,
-) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_radix_buf_u32(s: &str, radix: u32, buf: &mut Vec<u32>) -> Result<(), Error> {
     let parsed = buf;
 
     let mut char_indices = s.char_indices();
@@ -87,7 +94,7 @@ pub fn parse_ints_radix_buf_u32(
 
         if let Some((start, end)) = current {
             let n_str = &s[start..=end];
-            let n = u32::from_str_radix(n_str, radix)?;
+            let n = u32::from_str_radix(n_str, radix).map_err(|e| int_error(e, s, start, end))?;
             parsed.push(n);
             current = None;
         }
@@ -100,21 +107,17 @@ pub fn parse_ints_radix_buf_u32(
     Ok(())
 }
 
-pub fn parse_ints(s: &str) -> Result<Vec<i32>, std::num::ParseIntError> {
+pub fn parse_ints(s: &str) -> Result<Vec<i32>, Error> {
     let mut buf = Vec::new();
     parse_ints_buf(s, &mut buf)?;
     Ok(buf)
 }
 
-pub fn parse_ints_buf(s: &str, buf: &mut Vec<i32>) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_buf(s: &str, buf: &mut Vec<i32>) -> Result<(), Error> {
     parse_ints_radix_buf(s, 10, buf)
 }
 
-pub fn parse_ints_radix_buf(
-    s: &str,
-    radix: u32,
-    buf: &mut Vec<i32>,
-) -> Result<(), std::num::ParseIntError> {
+pub fn parse_ints_radix_buf(s: &str, radix: u32, buf: &mut Vec<i32>) -> Result<(), Error> {
     let parsed = buf;
 
     let mut char_indices = s.char_indices();
@@ -141,7 +144,7 @@ pub fn parse_ints_radix_buf(
 
         if let Some((start, end)) = current {
             let n_str = &s[start..=end];
-            let n = i32::from_str_radix(n_str, radix)?;
+            let n = i32::from_str_radix(n_str, radix).map_err(|e| int_error(e, s, start, end))?;
             parsed.push(n);
             current = None;
         }
@@ -154,11 +157,11 @@ pub fn parse_ints_radix_buf(
     Ok(())
 }
 
-pub fn parse_ints_i64(s: &str) -> Result<Vec<i64>, std::num::ParseIntError> {
+pub fn parse_ints_i64(s: &str) -> Result<Vec<i64>, Error> {
     parse_ints_radix_i64(s, 10)
 }
 
-pub fn parse_ints_radix_i64(s: &str, radix: u32) -> Result<Vec<i64>, std::num::ParseIntError> {
+pub fn parse_ints_radix_i64(s: &str, radix: u32) -> Result<Vec<i64>, Error> {
     let mut parsed = Vec::new();
 
     let mut char_indices = s.char_indices();
@@ -185,7 +188,7 @@ pub fn parse_ints_radix_i64(s: &str, radix: u32) -> Result<Vec<i64>, std::num::P
 
         if let Some((start, end)) = current {
             let n_str = &s[start..=end];
-            let n = i64::from_str_radix(n_str, radix)?;
+            let n = i64::from_str_radix(n_str, radix).map_err(|e| int_error(e, s, start, end))?;
             parsed.push(n);
             current = None;
         }
@@ -0,0 +1,377 @@
+//! Weighted shortest paths with Dijkstra's algorithm and A*, over any state
+//! type that can be hashed, plus adapters for searching [`Grid`]s and
+//! [`SparseGrid`]s by position or by position and facing.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use super::{Dir, Grid, SparseGrid, Vec2};
+
+/// The result of [`dijkstra`] or [`astar`]. States are only reported once
+/// their cost is final, so after stopping at a goal some of the explored
+/// states have no cost.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    cost: Vec<u64>,
+    settled: Vec<bool>,
+    /// for each state, every state it's reached from on a cheapest path
+    predecessors: Vec<Vec<usize>>,
+    /// for each state, the predecessor that gave it its cost, which was settled
+    /// before it, so following these always ends at a start
+    parent: Vec<Option<usize>>,
+    goals: Vec<usize>,
+}
+
+/// Dijkstra's algorithm from every state in `starts`. `neighbors` gives the
+/// states reachable from a state along with the cost of getting there. The
+/// search stops once every goal state with the lowest cost has been found, or
+/// explores everything reachable if `is_goal` is never true.
+pub fn dijkstra<S, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: N,
+    is_goal: G,
+) -> Search<S>
+where
+    S: Clone + Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, neighbors, |_| 0, is_goal)
+}
+
+/// A*, which is [`dijkstra`] guided towards the goal by `heuristic`. The
+/// heuristic must never overestimate the remaining cost, and must not drop by
+/// more than the cost of a step, or the results may not be the cheapest.
+pub fn astar<S, N, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<S>
+where
+    S: Clone + Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search {
+        states: vec![],
+        index: HashMap::new(),
+        cost: vec![],
+        settled: vec![],
+        predecessors: vec![],
+        parent: vec![],
+        goals: vec![],
+    };
+
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let id = search.intern(start);
+        if search.cost[id] != 0 {
+            search.cost[id] = 0;
+            queue.push(Reverse((heuristic(&search.states[id]), 0, id)));
+        }
+    }
+
+    let mut goal_cost = None;
+    while let Some(Reverse((estimate, cost, id))) = queue.pop() {
+        if search.settled[id] || cost > search.cost[id] {
+            continue;
+        }
+        if goal_cost.is_some_and(|goal| estimate > goal) {
+            break;
+        }
+        search.settled[id] = true;
+
+        let state = search.states[id].clone();
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(id);
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_id = search.intern(next);
+            let next_cost = cost + step;
+            match next_cost.cmp(&search.cost[next_id]) {
+                Ordering::Less => {
+                    search.cost[next_id] = next_cost;
+                    search.predecessors[next_id] = vec![id];
+                    search.parent[next_id] = Some(id);
+                    let estimate = next_cost + heuristic(&search.states[next_id]);
+                    queue.push(Reverse((estimate, next_cost, next_id)));
+                }
+                Ordering::Equal => search.predecessors[next_id].push(id),
+                Ordering::Greater => {}
+            }
+        }
+    }
+
+    search
+}
+
+impl<S: Clone + Hash + Eq> Search<S> {
+    fn intern(&mut self, state: S) -> usize {
+        if let Some(&id) = self.index.get(&state) {
+            return id;
+        }
+        let id = self.states.len();
+        self.index.insert(state.clone(), id);
+        self.states.push(state);
+        self.cost.push(u64::MAX);
+        self.settled.push(false);
+        self.predecessors.push(vec![]);
+        self.parent.push(None);
+        id
+    }
+
+    fn settled_id(&self, state: &S) -> Option<usize> {
+        self.index
+            .get(state)
+            .copied()
+            .filter(|&id| self.settled[id])
+    }
+
+    /// the cost of the cheapest path to a goal
+    pub fn cost(&self) -> Option<u64> {
+        self.goals.first().map(|&id| self.cost[id])
+    }
+
+    /// the goal states that were found, which all have the same cost
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&id| &self.states[id])
+    }
+
+    /// the cost of the cheapest path to `state`, if it's known
+    pub fn cost_to(&self, state: &S) -> Option<u64> {
+        self.settled_id(state).map(|id| self.cost[id])
+    }
+
+    /// the states `state` is reached from on its cheapest paths
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        let ids = self
+            .settled_id(state)
+            .map_or(&[][..], |id| &self.predecessors[id]);
+        ids.iter().map(|&id| &self.states[id])
+    }
+
+    /// a cheapest path from a start to the first goal, including both ends
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goals.first().map(|&id| self.path_from_id(id))
+    }
+
+    /// a cheapest path from a start to `state`, including both ends
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.settled_id(state).map(|id| self.path_from_id(id))
+    }
+
+    fn path_from_id(&self, mut id: usize) -> Vec<S> {
+        let mut path = vec![self.states[id].clone()];
+        // with zero-cost edges the predecessors can loop, but the parents can't
+        while let Some(prev) = self.parent[id] {
+            path.push(self.states[prev].clone());
+            id = prev;
+        }
+        path.reverse();
+        path
+    }
+
+    /// every state on any of the cheapest paths to any of the goals
+    pub fn on_cheapest_paths(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(&self.predecessors[id]);
+            }
+        }
+        seen.into_iter().map(|id| self.states[id].clone()).collect()
+    }
+}
+
+/// Grids that can be searched by position with [`steps`] and [`headings`].
+pub trait Cells {
+    type Cell;
+
+    /// the cell at `p`, or `None` if there is nothing there to step onto
+    fn cell(&self, p: Vec2) -> Option<&Self::Cell>;
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn cell(&self, p: Vec2) -> Option<&T> {
+        self.get(p.x, p.y)
+    }
+}
+
+/// Only the positions that are in the grid can be stepped onto.
+impl<T> Cells for SparseGrid<T> {
+    type Cell = T;
+
+    fn cell(&self, p: Vec2) -> Option<&T> {
+        self.get(&p)
+    }
+}
+
+/// Neighbors for searching `cells` by position: a step in each direction,
+/// costing `enter` of the cell stepped onto. Cells it returns `None` for are walls.
+pub fn steps<'a, C: Cells>(
+    cells: &'a C,
+    mut enter: impl FnMut(&C::Cell) -> Option<u64> + 'a,
+) -> impl FnMut(&Vec2) -> Vec<(Vec2, u64)> + 'a {
+    move |&p| {
+        Dir::ALL
+            .iter()
+            .filter_map(|d| {
+                let next = p + d.to_vec2();
+                Some((next, enter(cells.cell(next)?)?))
+            })
+            .collect()
+    }
+}
+
+/// A position along with the direction it's facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Heading {
+    pub pos: Vec2,
+    pub dir: Dir,
+}
+
+/// Neighbors for searching `cells` by [`Heading`]: a step forward costing
+/// `enter` of the cell stepped onto, like in [`steps`], or turning left or
+/// right on the spot for `turn_cost`.
+pub fn headings<'a, C: Cells>(
+    cells: &'a C,
+    mut enter: impl FnMut(&C::Cell) -> Option<u64> + 'a,
+    turn_cost: u64,
+) -> impl FnMut(&Heading) -> Vec<(Heading, u64)> + 'a {
+    move |&Heading { pos, dir }| {
+        let mut next = vec![
+            (
+                Heading {
+                    pos,
+                    dir: dir.turn_left(),
+                },
+                turn_cost,
+            ),
+            (
+                Heading {
+                    pos,
+                    dir: dir.turn_right(),
+                },
+                turn_cost,
+            ),
+        ];
+        let ahead = pos + dir.to_vec2();
+        if let Some(cost) = cells.cell(ahead).and_then(&mut enter) {
+            next.push((Heading { pos: ahead, dir }, cost));
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::vec2;
+
+    #[test]
+    fn all_cheapest_paths() {
+        // a diamond a -> {b, c} -> d, plus a more expensive direct edge a -> d
+        let edges = |s: &char| match s {
+            'a' => vec![('b', 1), ('c', 1), ('d', 5)],
+            'b' | 'c' => vec![('d', 2)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], edges, |&s| s == 'd');
+        assert_eq!(search.cost(), Some(3));
+        assert_eq!(search.path().unwrap().len(), 3);
+        assert_eq!(search.predecessors(&'d').count(), 2);
+        assert_eq!(search.on_cheapest_paths().len(), 4);
+    }
+
+    #[test]
+    fn grid_headings() {
+        let maze = Grid::from_str("#####\n#..E#\n#.#.#\n#S..#\n#####\n", |c| c).unwrap();
+        let start = Heading {
+            pos: vec2(1, 3),
+            dir: Dir::Right,
+        };
+        let end = vec2(3, 1);
+        let open = |c: &char| (*c != '#').then_some(1);
+
+        let search = dijkstra([start], headings(&maze, open, 1000), |h| h.pos == end);
+        assert_eq!(search.cost(), Some(1004));
+        assert_eq!(search.on_cheapest_paths().len(), 6);
+
+        let search = astar(
+            [start.pos],
+            steps(&maze, open),
+            |p| p.manhattan_distance(end) as u64,
+            |&p| p == end,
+        );
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(search.path().unwrap().first(), Some(&start.pos));
+    }
+
+    #[test]
+    fn free_turns() {
+        // turning on the spot costs nothing, so headings form zero-cost cycles
+        let maze = Grid::from_str("#####\n#..E#\n#.#.#\n#S..#\n#####\n", |c| c).unwrap();
+        let start = Heading {
+            pos: vec2(1, 3),
+            dir: Dir::Right,
+        };
+        let end = vec2(3, 1);
+        let open = |c: &char| (*c != '#').then_some(1);
+
+        let search = dijkstra([start], headings(&maze, open, 0), |h| h.pos == end);
+        assert_eq!(search.cost(), Some(4));
+        let path = search.path().unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last().map(|h| h.pos), Some(end));
+        assert!(search.path_to(&start).is_some_and(|p| p.len() == 1));
+        assert!(search.on_cheapest_paths().contains(&start));
+
+        // turning is free, so spinning on the spot is part of a cheapest path
+        // anywhere on either route, except at the end where the search stops
+        let mut expected: HashSet<Heading> = maze
+            .iter()
+            .filter(|&(p, &c)| c != '#' && vec2(p.0, p.1) != end)
+            .flat_map(|(p, _)| Dir::ALL.map(|dir| Heading { pos: p.into(), dir }))
+            .collect();
+        expected.insert(Heading {
+            pos: end,
+            dir: Dir::Up,
+        });
+        expected.insert(Heading {
+            pos: end,
+            dir: Dir::Right,
+        });
+        assert_eq!(search.on_cheapest_paths(), expected);
+
+        // c is reached at no cost both directly and through b
+        let edges = |s: &char| match s {
+            'a' => vec![('c', 0), ('b', 0)],
+            'b' => vec![('c', 0)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], edges, |&s| s == 'd');
+        assert_eq!(search.cost(), Some(1));
+        let mut before_c: Vec<_> = search.predecessors(&'c').collect();
+        before_c.sort();
+        assert_eq!(before_c, [&'a', &'b']);
+        assert_eq!(
+            search.on_cheapest_paths(),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+        assert_eq!(search.path().unwrap(), ['a', 'c', 'd']);
+    }
+}
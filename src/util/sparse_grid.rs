@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use super::{vec2, Vec2};
+use super::{Vec2, vec2};
+use crate::error::{Error, ErrorKind};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SparseGrid<T> {
@@ -41,43 +42,42 @@ impl<T> SparseGrid<T> {
         grid
     }
 
-    /// parses a string to a grid, determining width and height automatically based on newlines (`\n`)
-    pub fn from_str<F>(s: &str, mut f: F) -> Option<Self>
+    /// parses a string to a grid, one row per line, with the same rules as [`Grid::from_str`](super::Grid::from_str)
+    pub fn from_str<F>(s: &str, mut f: F) -> Result<Self, Error>
     where
         F: FnMut(char) -> Option<T>,
     {
         let mut grid = SparseGrid::new();
 
         let mut width = None;
-        let mut n = 0;
-        let mut y = 0;
-        for c in s.chars() {
-            if c == '\n' {
-                if let Some(w) = width {
-                    if n == w {
-                        n = 0;
-                        continue;
-                    } else {
-                        return None;
-                    }
-                } else {
-                    width = Some(n);
+        for (y, line) in s.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let mut n = 0;
+            for (x, c) in line.chars().enumerate() {
+                if let Some(t) = f(c) {
+                    grid.insert(vec2(x as i32, y as i32), t);
                 }
-
-                n = 0;
-                y += 1;
-                continue;
+                n += 1;
             }
 
-            let x = n;
-            if let Some(t) = f(c) {
-                grid.insert(vec2(x, y), t);
+            match width {
+                None => width = Some(n),
+                Some(w) if w != n => {
+                    return Err(Error::new(ErrorKind::RaggedRow {
+                        expected: w,
+                        found: n,
+                    })
+                    .with_line(y + 1));
+                }
+                Some(_) => {}
             }
-            n += 1;
         }
 
-        Some(grid)
+        if width.is_none() {
+            return Err(Error::other("grid is empty"));
+        }
+        Ok(grid)
     }
+
     pub fn get(&self, i: &Vec2) -> Option<&T> {
         self.data.get(i)
     }
@@ -91,6 +91,11 @@ impl<T> SparseGrid<T> {
         self.data.insert(i, v)
     }
 
+    /// removes the value at `i`, which leaves the bounds as they were
+    pub fn remove(&mut self, i: &Vec2) -> Option<T> {
+        self.data.remove(i)
+    }
+
     fn expand_bounds(&mut self, i: Vec2) {
         self.top_left.x = i32::min(i.x, self.top_left.x);
         self.top_left.y = i32::min(i.y, self.top_left.y);
@@ -1,4 +1,137 @@
-pub fn variance(pop: &[f32]) -> f32 {
-    let mean = pop.iter().sum::<f32>() / pop.len() as f32;
-    pop.iter().map(|s| (s - mean) * (s - mean)).sum::<f32>() / pop.len() as f32
+pub fn mean(pop: &[f64]) -> f64 {
+    pop.iter().sum::<f64>() / pop.len() as f64
+}
+
+/// population variance
+pub fn variance(pop: &[f64]) -> f64 {
+    let mean = mean(pop);
+    pop.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / pop.len() as f64
+}
+
+pub fn std_dev(pop: &[f64]) -> f64 {
+    variance(pop).sqrt()
+}
+
+pub fn min(pop: &[f64]) -> f64 {
+    pop.iter().copied().fold(f64::INFINITY, f64::min)
+}
+
+pub fn max(pop: &[f64]) -> f64 {
+    pop.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+}
+
+pub fn sorted(pop: &[f64]) -> Vec<f64> {
+    let mut sorted = pop.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+pub fn median(pop: &[f64]) -> f64 {
+    quantile_sorted(&sorted(pop), 0.5)
+}
+
+pub fn quantile(pop: &[f64], q: f64) -> f64 {
+    quantile_sorted(&sorted(pop), q)
+}
+
+/// the `q`-th quantile (`0.0..=1.0`) of an already sorted population,
+/// linearly interpolating between the two closest samples
+pub fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    assert!(!sorted.is_empty(), "quantile of an empty population");
+    assert!((0.0..=1.0).contains(&q), "quantile {q} is out of range");
+
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+/// returns the samples outside Tukey's fences, i.e. further than `1.5 * IQR`
+/// below the first or above the third quartile
+pub fn outliers(pop: &[f64]) -> Vec<f64> {
+    if pop.is_empty() {
+        return vec![];
+    }
+
+    let sorted = sorted(pop);
+    let q1 = quantile_sorted(&sorted, 0.25);
+    let q3 = quantile_sorted(&sorted, 0.75);
+    let iqr = q3 - q1;
+    let (lo, hi) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+    sorted.into_iter().filter(|&x| x < lo || hi < x).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub n: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub median: f64,
+    pub p5: f64,
+    pub p25: f64,
+    pub p75: f64,
+    pub p95: f64,
+    pub p99: f64,
+    pub outliers: usize,
+}
+
+impl Summary {
+    /// returns `None` for an empty population
+    pub fn new(pop: &[f64]) -> Option<Self> {
+        if pop.is_empty() {
+            return None;
+        }
+
+        let sorted = sorted(pop);
+        Some(Self {
+            n: pop.len(),
+            mean: mean(pop),
+            std_dev: std_dev(pop),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            median: quantile_sorted(&sorted, 0.5),
+            p5: quantile_sorted(&sorted, 0.05),
+            p25: quantile_sorted(&sorted, 0.25),
+            p75: quantile_sorted(&sorted, 0.75),
+            p95: quantile_sorted(&sorted, 0.95),
+            p99: quantile_sorted(&sorted, 0.99),
+            outliers: outliers(pop).len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantiles() {
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(quantile_sorted(&sorted, 0.0), 1.0);
+        assert_eq!(quantile_sorted(&sorted, 0.25), 2.0);
+        assert_eq!(quantile_sorted(&sorted, 0.75), 4.0);
+        assert_eq!(quantile_sorted(&sorted, 1.0), 5.0);
+        assert_eq!(quantile_sorted(&[10.0, 20.0], 0.25), 12.5);
+    }
+
+    #[test]
+    fn summary_and_outliers() {
+        let pop = [3.0, 1.0, 100.0, 2.0, 4.0];
+        // q1 = 2 and q3 = 4, so anything above 4 + 1.5 * 2 is an outlier
+        assert_eq!(outliers(&pop), [100.0]);
+        assert!(outliers(&[]).is_empty());
+
+        let summary = Summary::new(&pop).unwrap();
+        assert_eq!(summary.n, 5);
+        assert_eq!((summary.min, summary.max), (1.0, 100.0));
+        assert_eq!((summary.p25, summary.median, summary.p75), (2.0, 3.0, 4.0));
+        assert_eq!(summary.mean, 22.0);
+        assert_eq!(summary.outliers, 1);
+        assert_eq!(Summary::new(&[]), None);
+    }
 }
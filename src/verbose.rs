@@ -0,0 +1,23 @@
+//! Diagnostic output that is kept out of the answers. Everything printed via
+//! [`verbose!`](crate::verbose!) goes to stderr, and only when `-v` is passed.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::verbose::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,84 @@
+//! Re-runs a part whenever its input or example files change, by polling
+//! their modification times.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::runner::{self, Job, Outcome};
+use crate::solution::Answer;
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches `input` and the examples of `job`'s day, re-running the part and
+/// its examples on every change. `root` is the crate root that the example
+/// paths are relative to. Never returns.
+pub fn watch(job: Job, input: &str, root: &Path) -> ! {
+    let examples = job.solution.examples();
+    let mut files = vec![PathBuf::from(input)];
+    files.extend(examples.iter().map(|e| root.join(e.path)));
+
+    let mut last_modified = vec![None; files.len()];
+    let mut previous: Option<Answer> = None;
+    println!(
+        "watching {} for day {} part {}, press Ctrl-C to stop",
+        files
+            .iter()
+            .map(|f| f.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        job.solution.day(),
+        job.part
+    );
+
+    loop {
+        let modified: Vec<Option<SystemTime>> = files
+            .iter()
+            .map(|f| std::fs::metadata(f).and_then(|m| m.modified()).ok())
+            .collect();
+
+        if modified != last_modified {
+            last_modified = modified;
+            println!();
+
+            for (example, path) in examples.iter().zip(&files[1..]) {
+                let Some(expected) = example.expected(job.part) else {
+                    continue;
+                };
+                // read the example from disk, the embedded copy is only updated on rebuild
+                let result = runner::read_input(job, &path.to_string_lossy())
+                    .and_then(|text| job.solution.part(job.part, &text));
+                match result {
+                    Ok(actual) if &actual == expected => println!("example {}: ok", example.path),
+                    Ok(actual) => println!(
+                        "example {}: FAIL, expected {expected}, got {actual}",
+                        example.path
+                    ),
+                    Err(e) => println!("example {}: error: {e}", example.path),
+                }
+            }
+
+            let result = runner::run(job, input);
+            match result.outcome {
+                Outcome::Solved(answer) => {
+                    let change = match &previous {
+                        Some(p) if *p == answer => " (unchanged)".to_string(),
+                        Some(p) => format!(" (was {p})"),
+                        None => String::new(),
+                    };
+                    println!(
+                        "answer: {answer}{change} in {:.2?} (parse {:.2?}, solve {:.2?})",
+                        result.parse.unwrap_or_default() + result.solve,
+                        result.parse.unwrap_or_default(),
+                        result.solve
+                    );
+                    previous = Some(answer);
+                }
+                Outcome::Failed(e) => println!("error: {e}"),
+                Outcome::MissingInput => println!("waiting for {input} to exist"),
+                Outcome::TimedOut(t) => println!("timed out after {t:.0?}"),
+            }
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}